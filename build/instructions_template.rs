@@ -190,6 +190,8 @@ enum REPLCodePtr {
     DiscontiguousProperty,
     #[strum_discriminants(strum(props(Arity = "3", Name = "$dynamic_property")))]
     DynamicProperty,
+    #[strum_discriminants(strum(props(Arity = "4", Name = "$imported_from_property")))]
+    ImportedFromProperty,
     #[strum_discriminants(strum(props(Arity = "3", Name = "$abolish_clause")))]
     AbolishClause,
     #[strum_discriminants(strum(props(Arity = "3", Name = "$asserta")))]
@@ -349,6 +351,8 @@ enum SystemClauseType {
     OpDeclaration,
     #[strum_discriminants(strum(props(Arity = "7", Name = "$open")))]
     Open,
+    #[strum_discriminants(strum(props(Arity = "1", Name = "$open_memory_stream")))]
+    OpenMemoryStream,
     #[strum_discriminants(strum(props(Arity = "5", Name = "$set_stream_options")))]
     SetStreamOptions,
     #[strum_discriminants(strum(props(Arity = "2", Name = "$next_stream")))]
@@ -411,6 +415,8 @@ enum SystemClauseType {
     GetCurrentBlock,
     #[strum_discriminants(strum(props(Arity = "1", Name = "$get_current_scc_block")))]
     GetCurrentSCCBlock,
+    #[strum_discriminants(strum(props(Arity = "1", Name = "$get_global_clock")))]
+    GetGlobalClock,
     #[strum_discriminants(strum(props(Arity = "1", Name = "$get_cp")))]
     GetCutPoint,
     #[strum_discriminants(strum(props(Arity = "1", Name = "$get_double_quotes")))]
@@ -429,6 +435,8 @@ enum SystemClauseType {
     ReadFromChars,
     #[strum_discriminants(strum(props(Arity = "5", Name = "$read_term_from_chars")))]
     ReadTermFromChars,
+    #[strum_discriminants(strum(props(Arity = "2", Name = "$read_tokens_from_chars")))]
+    ReadTokensFromChars,
     #[strum_discriminants(strum(props(Arity = "1", Name = "$reset_block")))]
     ResetBlock,
     #[strum_discriminants(strum(props(Arity = "1", Name = "$reset_scc_block")))]
@@ -585,6 +593,10 @@ enum SystemClauseType {
     IsExpandedOrInlined,
     #[strum_discriminants(strum(props(Arity = "3", Name = "$get_clause_p")))]
     GetClauseP,
+    #[strum_discriminants(strum(props(Arity = "4", Name = "$get_clause_loc")))]
+    GetClauseLoc,
+    #[strum_discriminants(strum(props(Arity = "5", Name = "$invoke_clause_at_loc")))]
+    InvokeClauseAtLoc,
     #[strum_discriminants(strum(props(Arity = "6", Name = "$invoke_clause_at_p")))]
     InvokeClauseAtP,
     #[strum_discriminants(strum(props(Arity = "3", Name = "$get_from_attr_list")))]
@@ -767,6 +779,10 @@ enum InstructionTemplate {
     FloatFractionalPart(ArithmeticTerm, usize),
     #[strum_discriminants(strum(props(Arity = "1", Name = "float_integer_part")))]
     FloatIntegerPart(ArithmeticTerm, usize),
+    #[strum_discriminants(strum(props(Arity = "1", Name = "rational")))]
+    Rational(ArithmeticTerm, usize),
+    #[strum_discriminants(strum(props(Arity = "1", Name = "rationalize")))]
+    Rationalize(ArithmeticTerm, usize),
     #[strum_discriminants(strum(props(Arity = "1", Name = "neg")))]
     Neg(ArithmeticTerm, usize),
     #[strum_discriminants(strum(props(Arity = "1", Name = "plus")))]
@@ -1065,7 +1081,7 @@ pub fn to_functor(&self, mut h: usize) -> MachineStub {
 
                         functor!(
                             atom!("switch_on_constant"),
-                            [str(orig_h, 0)],
+                            [literal(heap_loc_as_cell!(orig_h + 2))],
                             [key_value_list_stub]
                         )
                     }
@@ -1073,7 +1089,7 @@ pub fn to_functor(&self, mut h: usize) -> MachineStub {
                         let mut key_value_list_stub = vec![];
                         let orig_h = h;
 
-                        h += 2; // skip the 2-cell "switch_on_constant" functor.
+                        h += 2; // skip the 2-cell "switch_on_structure" functor.
 
                         for ((name, arity), ptr) in structures.iter() {
                             let predicate_indicator_stub = functor!(
@@ -1099,7 +1115,7 @@ pub fn to_functor(&self, mut h: usize) -> MachineStub {
 
                         functor!(
                             atom!("switch_on_structure"),
-                            [str(orig_h, 0)],
+                            [literal(heap_loc_as_cell!(orig_h + 2))],
                             [key_value_list_stub]
                         )
                     }
@@ -1136,8 +1152,7 @@ fn arith_instr_unary_functor(
             at: &ArithmeticTerm,
             t: usize,
         ) -> MachineStub {
-            let at_stub = at.into_functor(arena);
-            functor!(name, [str(h, 0), fixnum(t)], [at_stub])
+            functor!(name, [arith_term(h, arena, *at), fixnum(t)])
         }
 
         fn arith_instr_bin_functor(
@@ -1148,13 +1163,39 @@ fn arith_instr_bin_functor(
             at_2: &ArithmeticTerm,
             t: usize,
         ) -> MachineStub {
-            let at_1_stub = at_1.into_functor(arena);
-            let at_2_stub = at_2.into_functor(arena);
-
             functor!(
                 name,
-                [str(h, 0), str(h, 1), fixnum(t)],
-                [at_1_stub, at_2_stub]
+                [arith_term(h, arena, *at_1), arith_term(h, arena, *at_2), fixnum(t)]
+            )
+        }
+
+        fn is_instr_functor(
+            h: usize,
+            call_name: Atom,
+            arena: &mut Arena,
+            r: RegType,
+            at: ArithmeticTerm,
+        ) -> MachineStub {
+            let rt_stub = reg_type_into_functor(r);
+
+            functor!(
+                call_name,
+                [atom(atom!("is")), fixnum(2), str(h, 0), arith_term(h, arena, at)],
+                [rt_stub]
+            )
+        }
+
+        fn compare_number_instr_functor(
+            h: usize,
+            call_name: Atom,
+            name: Atom,
+            arena: &mut Arena,
+            at_1: ArithmeticTerm,
+            at_2: ArithmeticTerm,
+        ) -> MachineStub {
+            functor!(
+                call_name,
+                [atom(name), fixnum(2), arith_term(h, arena, at_1), arith_term(h, arena, at_2)]
             )
         }
 
@@ -1499,6 +1540,12 @@ fn to_functor(&self, h: usize, arena: &mut Arena) -> MachineStub {
                     &Instruction::FloatIntegerPart(ref at, t) => {
                         arith_instr_unary_functor(h, atom!("float_integer_part"), arena, at, t)
                     }
+                    &Instruction::Rational(ref at, t) => {
+                        arith_instr_unary_functor(h, atom!("rational"), arena, at, t)
+                    }
+                    &Instruction::Rationalize(ref at, t) => {
+                        arith_instr_unary_functor(h, atom!("rationalize"), arena, at, t)
+                    }
                     &Instruction::Neg(ref at, t) => arith_instr_unary_functor(
                         h,
                         atom!("-"),
@@ -1565,13 +1612,6 @@ fn to_functor(&self, h: usize, arena: &mut Arena) -> MachineStub {
                     &Instruction::CallTermLessThanOrEqual |
                     &Instruction::CallTermEqual |
                     &Instruction::CallTermNotEqual |
-                    &Instruction::CallNumberGreaterThan(..) |
-                    &Instruction::CallNumberLessThan(..) |
-                    &Instruction::CallNumberGreaterThanOrEqual(..) |
-                    &Instruction::CallNumberLessThanOrEqual(..) |
-                    &Instruction::CallNumberEqual(..) |
-                    &Instruction::CallNumberNotEqual(..) |
-                    &Instruction::CallIs(..) |
                     &Instruction::CallAcyclicTerm |
                     &Instruction::CallArg |
                     &Instruction::CallCompare |
@@ -1591,19 +1631,12 @@ fn to_functor(&self, h: usize, arena: &mut Arena) -> MachineStub {
                     &Instruction::ExecuteTermLessThanOrEqual |
                     &Instruction::ExecuteTermEqual |
                     &Instruction::ExecuteTermNotEqual |
-                    &Instruction::ExecuteNumberGreaterThan(..) |
-                    &Instruction::ExecuteNumberLessThan(..) |
-                    &Instruction::ExecuteNumberGreaterThanOrEqual(..) |
-                    &Instruction::ExecuteNumberLessThanOrEqual(..) |
-                    &Instruction::ExecuteNumberEqual(..) |
-                    &Instruction::ExecuteNumberNotEqual(..) |
                     &Instruction::ExecuteAcyclicTerm |
                     &Instruction::ExecuteArg |
                     &Instruction::ExecuteCompare |
                     &Instruction::ExecuteCopyTerm |
                     &Instruction::ExecuteFunctor |
                     &Instruction::ExecuteGround |
-                    &Instruction::ExecuteIs(..) |
                     &Instruction::ExecuteKeySort |
                     &Instruction::ExecuteSort |
                     &Instruction::ExecuteGetNumber(_) => {
@@ -1617,19 +1650,12 @@ fn to_functor(&self, h: usize, arena: &mut Arena) -> MachineStub {
                     &Instruction::DefaultCallTermLessThanOrEqual |
                     &Instruction::DefaultCallTermEqual |
                     &Instruction::DefaultCallTermNotEqual |
-                    &Instruction::DefaultCallNumberGreaterThan(..) |
-                    &Instruction::DefaultCallNumberLessThan(..) |
-                    &Instruction::DefaultCallNumberGreaterThanOrEqual(..) |
-                    &Instruction::DefaultCallNumberLessThanOrEqual(..) |
-                    &Instruction::DefaultCallNumberEqual(..) |
-                    &Instruction::DefaultCallNumberNotEqual(..) |
                     &Instruction::DefaultCallAcyclicTerm |
                     &Instruction::DefaultCallArg |
                     &Instruction::DefaultCallCompare |
                     &Instruction::DefaultCallCopyTerm |
                     &Instruction::DefaultCallFunctor |
                     &Instruction::DefaultCallGround |
-                    &Instruction::DefaultCallIs(..) |
                     &Instruction::DefaultCallKeySort |
                     &Instruction::DefaultCallSort |
                     &Instruction::DefaultCallGetNumber(_) => {
@@ -1643,25 +1669,66 @@ fn to_functor(&self, h: usize, arena: &mut Arena) -> MachineStub {
                     &Instruction::DefaultExecuteTermLessThanOrEqual |
                     &Instruction::DefaultExecuteTermEqual |
                     &Instruction::DefaultExecuteTermNotEqual |
-                    &Instruction::DefaultExecuteNumberGreaterThan(..) |
-                    &Instruction::DefaultExecuteNumberLessThan(..) |
-                    &Instruction::DefaultExecuteNumberGreaterThanOrEqual(..) |
-                    &Instruction::DefaultExecuteNumberLessThanOrEqual(..) |
-                    &Instruction::DefaultExecuteNumberEqual(..) |
-                    &Instruction::DefaultExecuteNumberNotEqual(..) |
                     &Instruction::DefaultExecuteAcyclicTerm |
                     &Instruction::DefaultExecuteArg |
                     &Instruction::DefaultExecuteCompare |
                     &Instruction::DefaultExecuteCopyTerm |
                     &Instruction::DefaultExecuteFunctor |
                     &Instruction::DefaultExecuteGround |
-                    &Instruction::DefaultExecuteIs(..) |
                     &Instruction::DefaultExecuteKeySort |
                     &Instruction::DefaultExecuteSort |
                     &Instruction::DefaultExecuteGetNumber(_) => {
                         let (name, arity) = self.to_name_and_arity();
                         functor!(atom!("execute_default"), [atom(name), fixnum(arity)])
                     }
+                    &Instruction::CallNumberGreaterThan(at_1, at_2) |
+                    &Instruction::CallNumberLessThan(at_1, at_2) |
+                    &Instruction::CallNumberGreaterThanOrEqual(at_1, at_2) |
+                    &Instruction::CallNumberLessThanOrEqual(at_1, at_2) |
+                    &Instruction::CallNumberEqual(at_1, at_2) |
+                    &Instruction::CallNumberNotEqual(at_1, at_2) => {
+                        let (name, _) = self.to_name_and_arity();
+                        compare_number_instr_functor(h, atom!("call"), name, arena, at_1, at_2)
+                    }
+                    &Instruction::ExecuteNumberGreaterThan(at_1, at_2) |
+                    &Instruction::ExecuteNumberLessThan(at_1, at_2) |
+                    &Instruction::ExecuteNumberGreaterThanOrEqual(at_1, at_2) |
+                    &Instruction::ExecuteNumberLessThanOrEqual(at_1, at_2) |
+                    &Instruction::ExecuteNumberEqual(at_1, at_2) |
+                    &Instruction::ExecuteNumberNotEqual(at_1, at_2) => {
+                        let (name, _) = self.to_name_and_arity();
+                        compare_number_instr_functor(h, atom!("execute"), name, arena, at_1, at_2)
+                    }
+                    &Instruction::DefaultCallNumberGreaterThan(at_1, at_2) |
+                    &Instruction::DefaultCallNumberLessThan(at_1, at_2) |
+                    &Instruction::DefaultCallNumberGreaterThanOrEqual(at_1, at_2) |
+                    &Instruction::DefaultCallNumberLessThanOrEqual(at_1, at_2) |
+                    &Instruction::DefaultCallNumberEqual(at_1, at_2) |
+                    &Instruction::DefaultCallNumberNotEqual(at_1, at_2) => {
+                        let (name, _) = self.to_name_and_arity();
+                        compare_number_instr_functor(h, atom!("call_default"), name, arena, at_1, at_2)
+                    }
+                    &Instruction::DefaultExecuteNumberGreaterThan(at_1, at_2) |
+                    &Instruction::DefaultExecuteNumberLessThan(at_1, at_2) |
+                    &Instruction::DefaultExecuteNumberGreaterThanOrEqual(at_1, at_2) |
+                    &Instruction::DefaultExecuteNumberLessThanOrEqual(at_1, at_2) |
+                    &Instruction::DefaultExecuteNumberEqual(at_1, at_2) |
+                    &Instruction::DefaultExecuteNumberNotEqual(at_1, at_2) => {
+                        let (name, _) = self.to_name_and_arity();
+                        compare_number_instr_functor(h, atom!("execute_default"), name, arena, at_1, at_2)
+                    }
+                    &Instruction::CallIs(r, at) => {
+                        is_instr_functor(h, atom!("call"), arena, r, at)
+                    }
+                    &Instruction::ExecuteIs(r, at) => {
+                        is_instr_functor(h, atom!("execute"), arena, r, at)
+                    }
+                    &Instruction::DefaultCallIs(r, at) => {
+                        is_instr_functor(h, atom!("call_default"), arena, r, at)
+                    }
+                    &Instruction::DefaultExecuteIs(r, at) => {
+                        is_instr_functor(h, atom!("execute_default"), arena, r, at)
+                    }
                     &Instruction::CallIsAtom(r) |
                     &Instruction::CallIsAtomic(r) |
                     &Instruction::CallIsCompound(r) |
@@ -1725,6 +1792,8 @@ fn to_functor(&self, h: usize, arena: &mut Arena) -> MachineStub {
                     &Instruction::CallCompileInlineOrExpandedGoal |
                     &Instruction::CallIsExpandedOrInlined |
                     &Instruction::CallGetClauseP |
+                    &Instruction::CallGetClauseLoc |
+                    &Instruction::CallInvokeClauseAtLoc |
                     &Instruction::CallInvokeClauseAtP |
                     &Instruction::CallGetFromAttributedVarList |
                     &Instruction::CallPutToAttributedVarList |
@@ -1769,6 +1838,7 @@ fn to_functor(&self, h: usize, arena: &mut Arena) -> MachineStub {
                     &Instruction::CallNumberToCodes |
                     &Instruction::CallOpDeclaration |
                     &Instruction::CallOpen |
+                    &Instruction::CallOpenMemoryStream |
                     &Instruction::CallSetStreamOptions |
                     &Instruction::CallNextStream |
                     &Instruction::CallPartialStringTail |
@@ -1800,6 +1870,7 @@ fn to_functor(&self, h: usize, arena: &mut Arena) -> MachineStub {
                     &Instruction::CallGetBall |
                     &Instruction::CallGetCurrentBlock |
                     &Instruction::CallGetCurrentSCCBlock |
+                    &Instruction::CallGetGlobalClock |
                     &Instruction::CallGetCutPoint |
                     &Instruction::CallGetDoubleQuotes |
                     &Instruction::CallGetUnknown |
@@ -1821,6 +1892,7 @@ fn to_functor(&self, h: usize, arena: &mut Arena) -> MachineStub {
                     &Instruction::CallQuotedToken |
                     &Instruction::CallReadFromChars |
                     &Instruction::CallReadTermFromChars |
+                    &Instruction::CallReadTokensFromChars |
                     &Instruction::CallResetBlock |
                     &Instruction::CallResetSCCBlock |
                     &Instruction::CallReturnFromVerifyAttr |
@@ -1902,6 +1974,7 @@ fn to_functor(&self, h: usize, arena: &mut Arena) -> MachineStub {
                     &Instruction::CallMultifileProperty |
                     &Instruction::CallDiscontiguousProperty |
                     &Instruction::CallDynamicProperty |
+                    &Instruction::CallImportedFromProperty |
                     &Instruction::CallAbolishClause |
                     &Instruction::CallAsserta |
                     &Instruction::CallAssertz |
@@ -1962,6 +2035,8 @@ fn to_functor(&self, h: usize, arena: &mut Arena) -> MachineStub {
                     &Instruction::ExecuteCompileInlineOrExpandedGoal |
                     &Instruction::ExecuteIsExpandedOrInlined |
                     &Instruction::ExecuteGetClauseP |
+                    &Instruction::ExecuteGetClauseLoc |
+                    &Instruction::ExecuteInvokeClauseAtLoc |
                     &Instruction::ExecuteInvokeClauseAtP |
                     &Instruction::ExecuteGetFromAttributedVarList |
                     &Instruction::ExecutePutToAttributedVarList |
@@ -2006,6 +2081,7 @@ fn to_functor(&self, h: usize, arena: &mut Arena) -> MachineStub {
                     &Instruction::ExecuteNumberToCodes |
                     &Instruction::ExecuteOpDeclaration |
                     &Instruction::ExecuteOpen |
+                    &Instruction::ExecuteOpenMemoryStream |
                     &Instruction::ExecuteSetStreamOptions |
                     &Instruction::ExecuteNextStream |
                     &Instruction::ExecutePartialStringTail |
@@ -2037,6 +2113,7 @@ fn to_functor(&self, h: usize, arena: &mut Arena) -> MachineStub {
                     &Instruction::ExecuteGetBall |
                     &Instruction::ExecuteGetCurrentBlock |
                     &Instruction::ExecuteGetCurrentSCCBlock |
+                    &Instruction::ExecuteGetGlobalClock |
                     &Instruction::ExecuteGetCutPoint |
                     &Instruction::ExecuteGetDoubleQuotes |
                     &Instruction::ExecuteGetUnknown |
@@ -2058,6 +2135,7 @@ fn to_functor(&self, h: usize, arena: &mut Arena) -> MachineStub {
                     &Instruction::ExecuteQuotedToken |
                     &Instruction::ExecuteReadFromChars |
                     &Instruction::ExecuteReadTermFromChars |
+                    &Instruction::ExecuteReadTokensFromChars |
                     &Instruction::ExecuteResetBlock |
                     &Instruction::ExecuteResetSCCBlock |
                     &Instruction::ExecuteReturnFromVerifyAttr |
@@ -2139,6 +2217,7 @@ fn to_functor(&self, h: usize, arena: &mut Arena) -> MachineStub {
                     &Instruction::ExecuteMultifileProperty |
                     &Instruction::ExecuteDiscontiguousProperty |
                     &Instruction::ExecuteDynamicProperty |
+                    &Instruction::ExecuteImportedFromProperty |
                     &Instruction::ExecuteAbolishClause |
                     &Instruction::ExecuteAsserta |
                     &Instruction::ExecuteAssertz |
@@ -2366,6 +2445,7 @@ fn attributeless_enum<T: ToDeriveInput>() -> Vec<Variant> {
     let mut clause_type_name_arms = vec![];
     let mut is_inbuilt_arms = vec![];
     let mut is_inlined_arms = vec![];
+    let mut inbuilt_keys = vec![];
 
     is_inbuilt_arms.push(quote! {
         (atom!(":-"), 1 | 2)
@@ -2428,6 +2508,10 @@ fn attributeless_enum<T: ToDeriveInput>() -> Vec<Variant> {
             (atom!(#name), #arity)
         });
 
+        inbuilt_keys.push(quote! {
+            (atom!(#name), #arity)
+        });
+
         is_inlined_arms.push(quote! {
             (atom!(#name), #arity)
         });
@@ -2460,6 +2544,10 @@ fn attributeless_enum<T: ToDeriveInput>() -> Vec<Variant> {
         is_inbuilt_arms.push(quote! {
             (atom!(#name), #arity)
         });
+
+        inbuilt_keys.push(quote! {
+            (atom!(#name), #arity)
+        });
     }
 
     for (name, arity, variant) in instr_data.builtin_type_variants {
@@ -2526,6 +2614,10 @@ fn attributeless_enum<T: ToDeriveInput>() -> Vec<Variant> {
         is_inbuilt_arms.push(quote! {
             (atom!(#name), #arity)
         });
+
+        inbuilt_keys.push(quote! {
+            (atom!(#name), #arity)
+        });
     }
 
     for (name, arity, variant) in instr_data.inlined_type_variants {
@@ -2589,6 +2681,10 @@ fn attributeless_enum<T: ToDeriveInput>() -> Vec<Variant> {
             (atom!(#name), #arity)
         });
 
+        inbuilt_keys.push(quote! {
+            (atom!(#name), #arity)
+        });
+
         is_inlined_arms.push(quote! {
             (atom!(#name), #arity)
         });
@@ -2684,6 +2780,10 @@ fn attributeless_enum<T: ToDeriveInput>() -> Vec<Variant> {
                 (atom!(#name), _)
             }
         } else {
+            inbuilt_keys.push(quote! {
+                (atom!(#name), #arity)
+            });
+
             quote! {
                 (atom!(#name), #arity)
             }
@@ -2758,6 +2858,10 @@ fn attributeless_enum<T: ToDeriveInput>() -> Vec<Variant> {
         is_inbuilt_arms.push(quote! {
             (atom!(#name), #arity)
         });
+
+        inbuilt_keys.push(quote! {
+            (atom!(#name), #arity)
+        });
     }
 
     for (name, arity, variant) in instr_data.clause_type_variants {
@@ -3210,6 +3314,10 @@ pub fn is_inlined(name: Atom, arity: usize) -> bool {
                     #(#is_inlined_arms)|*
                 )
             }
+
+            pub fn inbuilt_keys() -> impl Iterator<Item = (Atom, usize)> {
+                [#(#inbuilt_keys),*].into_iter()
+            }
         }
 
         #[derive(Clone, Debug)]
@@ -208,6 +208,8 @@ fn get_unary_instr(
             atom!("ceiling") => Ok(Instruction::Ceiling(a1, t)),
             atom!("floor") => Ok(Instruction::Floor(a1, t)),
             atom!("float_integer_part") => Ok(Instruction::FloatIntegerPart(a1, t)),
+            atom!("rational") => Ok(Instruction::Rational(a1, t)),
+            atom!("rationalize") => Ok(Instruction::Rationalize(a1, t)),
             atom!("float_fractional_part") => Ok(Instruction::FloatFractionalPart(a1, t)),
             atom!("sign") => Ok(Instruction::Sign(a1, t)),
             atom!("\\") => Ok(Instruction::BitwiseComplement(a1, t)),
@@ -305,6 +307,38 @@ fn instr_from_clause(
         }
     }
 
+    // evaluate +, - and * over two fixnum operands at compile time,
+    // replacing them with their result. anything that would overflow
+    // a fixnum, or raise an evaluation error, is left to the runtime.
+    fn fold_constant_op(&mut self, name: Atom, arity: usize) -> bool {
+        if arity != 2 || self.interm.len() < 2 {
+            return false;
+        }
+
+        let (n1, n2) = match &self.interm[self.interm.len() - 2..] {
+            &[ArithmeticTerm::Number(Number::Fixnum(n1)), ArithmeticTerm::Number(Number::Fixnum(n2))] => {
+                (n1.get_num(), n2.get_num())
+            }
+            _ => return false,
+        };
+
+        let result = match name {
+            atom!("+") => n1.checked_add(n2),
+            atom!("-") => n1.checked_sub(n2),
+            atom!("*") => n1.checked_mul(n2),
+            _ => None,
+        };
+
+        match result.map(Fixnum::build_with_checked) {
+            Some(Ok(n)) => {
+                self.interm.truncate(self.interm.len() - 2);
+                self.interm.push(ArithmeticTerm::Number(Number::Fixnum(n)));
+                true
+            }
+            _ => false,
+        }
+    }
+
     pub(crate) fn compile_is(
         &mut self,
         src: &'a Term,
@@ -342,7 +376,9 @@ pub(crate) fn compile_is(
                     self.interm.push(ArithmeticTerm::Reg(r));
                 }
                 ArithTermRef::Op(name, arity) => {
-                    code.push_back(self.instr_from_clause(name, arity)?);
+                    if !self.fold_constant_op(name, arity) {
+                        code.push_back(self.instr_from_clause(name, arity)?);
+                    }
                 }
             }
         }
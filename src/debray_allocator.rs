@@ -146,6 +146,7 @@ pub(crate) struct DebrayAllocator {
     pub(crate) in_tail_position: bool,
     arg_c: usize,
     temp_lb: usize,
+    temp_ub: usize, // one past the greatest temp. register allocated.
     perm_lb: usize,
     arity: usize, // 0 if not at head.
     shallow_temp_mappings: IndexMap<usize, usize, FxBuildHasher>,
@@ -382,6 +383,7 @@ fn evacuate_arg<'a, Target: CompilationTarget<'a>>(
                     .allocation
                     .set_register(r.reg_num());
                 self.in_use.insert(r.reg_num());
+                self.temp_ub = std::cmp::max(self.temp_ub, r.reg_num() + 1);
             }
         };
     }
@@ -665,6 +667,7 @@ fn new() -> DebrayAllocator {
             arity: 0,
             arg_c: 1,
             temp_lb: 1,
+            temp_ub: 0,
             perm_lb: 1,
             shallow_temp_mappings: IndexMap::with_hasher(FxBuildHasher::default()),
             in_use: BitSet::default(),
@@ -721,6 +724,7 @@ fn mark_non_var<'a, Target: CompilationTarget<'a>>(
             _ if r.reg_num() == 0 => RegType::Temp(self.alloc_reg_to_non_var()),
             _ => {
                 self.in_use.insert(r.reg_num());
+                self.temp_ub = std::cmp::max(self.temp_ub, r.reg_num() + 1);
                 r
             }
         };
@@ -819,6 +823,7 @@ fn mark_reserved_var<'a, Target: CompilationTarget<'a>>(
 
         if !r.is_perm() {
             self.shallow_temp_mappings.insert(o, var_num);
+            self.temp_ub = std::cmp::max(self.temp_ub, o + 1);
         } else if r.is_perm() && is_new_var {
             self.branch_stack.add_branch_occurrence(var_num);
         }
@@ -899,10 +904,11 @@ fn reset_arg(&mut self, arity: usize) {
         self.arity = 0;
         self.arg_c = 1;
         self.temp_lb = arity + 1;
+        self.temp_ub = 0;
     }
 
     #[inline(always)]
     fn max_reg_allocated(&self) -> usize {
-        std::cmp::max(self.temp_lb, self.arg_c)
+        std::cmp::max(std::cmp::max(self.temp_lb, self.arg_c), self.temp_ub)
     }
 }
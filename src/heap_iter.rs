@@ -398,6 +398,47 @@ fn forward_if_referent_marked(&mut self, loc: IterStackLoc) {
         );
     }
 
+    // a forwarded variable may point into the middle of a chain of
+    // bound variables, e.g. at a list cell bound to a younger variable
+    // after the cell itself was visited. report the unbound variable at
+    // the end of the chain, as it was when first visited, so every
+    // occurrence of a variable is seen at the same location. the mark
+    // and forwarding bits of cell are kept.
+    pub(crate) fn var_chain_root(&self, cell: HeapCellValue) -> HeapCellValue {
+        if cell.get_tag() != HeapCellValueTag::Var {
+            return cell;
+        }
+
+        let start = cell.get_value() as usize;
+        let mut root = heap_loc_as_cell!(start);
+
+        while let HeapCellValueTag::Var = root.get_tag() {
+            let h = root.get_value() as usize;
+            let referent = self.heap[h];
+            let vh = referent.get_value() as usize;
+
+            if vh == start {
+                return cell;
+            } else if vh == h {
+                break;
+            }
+
+            match referent.get_tag() {
+                HeapCellValueTag::Var => root = heap_loc_as_cell!(vh),
+                HeapCellValueTag::AttrVar => root = attr_var_as_cell!(vh),
+                _ => break,
+            }
+        }
+
+        if root.get_value() as usize == start {
+            return cell;
+        }
+
+        root.set_mark_bit(cell.get_mark_bit());
+        root.set_forwarding_bit(cell.get_forwarding_bit());
+        root
+    }
+
     fn follow(&mut self) -> Option<HeapCellValue> {
         while let Some(h) = self.stack.pop() {
             if h.is_pending_mark() {
@@ -419,7 +460,7 @@ fn follow(&mut self) -> Option<HeapCellValue> {
             if cell.get_forwarding_bit() {
                 let copy = *cell;
                 cell.set_forwarding_bit(false);
-                return Some(copy);
+                return Some(self.var_chain_root(copy));
             } else if cell.get_mark_bit() && !is_readable_marked {
                 cell.set_mark_bit(false);
                 continue;
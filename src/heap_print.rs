@@ -809,7 +809,7 @@ fn format_clause(
     }
 
     fn offset_as_string(&mut self, h: IterStackLoc) -> Option<String> {
-        let cell = self.iter.read_cell(h);
+        let cell = self.iter.var_chain_root(self.iter.read_cell(h));
 
         if let Some(var) = self.var_names.get(&cell) {
             read_heap_cell!(cell,
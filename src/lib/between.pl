@@ -5,7 +5,7 @@ follow some property. `library(clpz)` provides another way of reasoning about
 integers that may also be interesting.
 */
 
-:- module(between, [between/3, gen_int/1, gen_nat/1, numlist/2, numlist/3, repeat/1]).
+:- module(between, [between/3, between/4, gen_int/1, gen_nat/1, numlist/2, numlist/3, repeat/1]).
 
 %% TODO: numlist/5.
 
@@ -50,6 +50,49 @@ between_(Lower, Upper, Lower1) :-
    ).
 between_(Lower, Lower, Lower).
 
+%% between(+Lower, +Upper, +Step, -X).
+%
+% Like `between/3`, but X only takes every Step-th integer counting up
+% from Lower, i.e. _X = Lower + K * Step_ for some natural number K.
+% Step must be a positive integer. Upper itself is a solution only if
+% it is reached by a whole number of steps.
+%
+% Examples:
+%
+% ```
+% ?- findall(X, between(0, 10, 2, X), Xs).
+%    Xs = [0,2,4,6,8,10].
+% ?- between(1, 10, 3, 7).
+%    true.
+% ?- between(1, 10, 3, 8).
+%    false.
+% ```
+between(Lower, Upper, Step, X) :-
+    must_be(integer, Lower),
+    must_be(integer, Upper),
+    must_be(integer, Step),
+    (   Step > 0 ->
+        true
+    ;   domain_error(positive_integer, Step, between/4)
+    ),
+    can_be(integer, X),
+    (   nonvar(X) ->
+        Lower =< X,
+        X =< Upper,
+        (X - Lower) mod Step =:= 0
+    ;   Lower =< Upper,
+        between_(Lower, Upper, Step, X)
+    ).
+
+between_(Lower, Upper, Step, X) :-
+   Lower1 is Lower + Step,
+   Lower1 =< Upper,
+   !,
+   (  X = Lower
+   ;  between_(Lower1, Upper, Step, X)
+   ).
+between_(Lower, _, _, Lower).
+
 enumerate_nats(I, I).
 enumerate_nats(I0, N) :-
     I1 is I0 + 1,
@@ -745,7 +745,7 @@ parse_read_term_options_(E,_) :-
 % Read Term from the stream Stream. It supports several options:
 %  * `variables(-Vars)` unifies Vars with a list of variables in the term. Similar to do `term_variables/2` with the new term.
 %  * `variable_names(-Vars)` unifies Vars with a list `Name=Var` with Name describing the variable name and Var the variable itself that appears in Term.
-%  * `singletons` similar to `variable_names` but only reports variables occurring only once in Term.
+%  * `singletons(-Vars)` similar to `variable_names` but only reports named variables occurring only once in Term. Anonymous variables (`_`) are never reported.
 read_term(Stream, Term, Options) :-
     parse_read_term_options(Options, [Singletons, VariableNames, Variables], read_term/3),
     '$read_term'(Stream, Term, Singletons, Variables, VariableNames).
@@ -1134,7 +1134,10 @@ setof(Template, Goal, Solution) :-
           '$fail'
        ;  '$head_is_dynamic'(Module, H) ->
           '$clause_body_is_valid'(B),
-          Module:'$clause'(H, B)
+          (  Module == user ->
+             '$clause'(H, B)
+          ;  Module:'$clause'(H, B)
+          )
        ;  throw(error(permission_error(access, private_procedure, Name/Arity),
                       clause/2))
        )
@@ -12,7 +12,12 @@ read and write chars.
                     get_n_chars/3,
                     get_line_to_chars/3,
                     read_from_chars/2,
+                    read_line_to_codes/2,
+                    read_line_to_string/2,
                     read_term_from_chars/3,
+                    read_tokens_from_chars/2,
+                    read_tokens_from_codes/2,
+                    sub_string/5,
                     write_term_to_chars/3,
                     chars_base64/3]).
 
@@ -224,6 +229,37 @@ read_term_from_chars(Chars, Term, Options) :-
     builtins:parse_read_term_options(Options, [Singletons, VariableNames, Variables], read_term_from_chars/3),
     '$read_term_from_chars'(Chars, Term, Singletons, Variables, VariableNames).
 
+%% read_tokens_from_chars(+Chars, -Tokens).
+%
+% Tokens is the list of tokens the Prolog reader sees in Chars,
+% ending where the input does. Each token is one of:
+%
+%  * `name(Atom)`, an atom or a single character;
+%  * `var(Name)`, a variable, with Name its name as an atom;
+%  * `number(N)`, an unsigned number;
+%  * `string(S)`, a double-quoted list;
+%  * `punct(P)`, one of `(`, `)`, `[`, `]`, `{`, `}`, `|` and `,`;
+%  * `open_ct`, an opening parenthesis directly after a name, as in
+%    functional notation;
+%  * `end`, the end token `.` of a clause.
+%
+% ```
+% ?- read_tokens_from_chars("f(X, 1).", Ts).
+%    Ts = [name(f),open_ct,var('X'),punct(','),number(1),punct(')'),end].
+% ```
+read_tokens_from_chars(Chars, Tokens) :-
+    must_be(chars, Chars),
+    '$read_tokens_from_chars'(Chars, Tokens).
+
+%% read_tokens_from_codes(+Codes, -Tokens).
+%
+% Like `read_tokens_from_chars/2`, but reads from a list of codes.
+read_tokens_from_codes(Codes, Tokens) :-
+    must_be(list, Codes),
+    atom_codes(Atom, Codes),
+    atom_chars(Atom, Chars),
+    read_tokens_from_chars(Chars, Tokens).
+
 %% write_term_to_chars(+Term, +Options, -Chars).
 %
 % Given a Term which is a Prolog term and a set of options, Chars is
@@ -321,6 +357,34 @@ get_line_to_chars(Stream, Cs0, Cs) :-
             )
         ).
 
+%% read_line_to_string(+Stream, -Line).
+%
+% Reads the next line from Stream as a string, without the trailing
+% `\n` (or `\r\n`). Line is `end_of_file` if Stream is at its end.
+read_line_to_string(Stream, Line) :-
+        get_line_to_chars(Stream, Cs, []),
+        (   Cs == [] -> Line = end_of_file
+        ;   line_without_newline(Cs, Line)
+        ).
+
+%% read_line_to_codes(+Stream, -Codes).
+%
+% Like `read_line_to_string/2`, but reads the line as a list of codes.
+% Codes is `-1` if Stream is at its end.
+read_line_to_codes(Stream, Codes) :-
+        read_line_to_string(Stream, Line),
+        (   Line == end_of_file -> Codes = -1
+        ;   maplist(char_code, Line, Codes)
+        ).
+
+line_without_newline(Cs0, Cs) :-
+        (   append(Cs1, ['\n'], Cs0) ->
+            (   append(Cs, ['\r'], Cs1) -> true
+            ;   Cs = Cs1
+            )
+        ;   Cs = Cs0
+        ).
+
 %% get_n_chars(+Stream, ?N, -Chars).
 %
 % Read N chars from stream Stream. N can be an integer, in that case
@@ -393,3 +457,113 @@ chars_base64(Cs, Bs, Options) :-
             ;   '$chars_base64'(Cs, Bs, Padding, Charset)
             )
         ).
+
+%% sub_string(+String, ?Before, ?Length, ?After, ?SubString).
+%
+% Like `sub_atom/5`, but String and SubString are strings, i.e. lists
+% of characters. SubString starts Before characters into String, is
+% Length characters long, and is followed by After characters. The
+% substring is taken directly if its position is determined by the
+% arguments, and otherwise all matching substrings are enumerated on
+% backtracking:
+%
+% ```
+% ?- sub_string("abcde", 1, 3, A, Sub).
+%    A = 1, Sub = "bcd".
+% ?- sub_string("abab", B, _, _, "ab").
+%    B = 0
+% ;  B = 2.
+% ?- sub_string("abab", B, _, _, "ba").
+%    B = 1
+% ;  false.
+% ```
+
+sub_string(String, Before, Length, After, SubString) :-
+        must_be(chars, String),
+        can_be(chars, SubString),
+        can_be(integer, Before),
+        can_be(integer, Length),
+        can_be(integer, After),
+        (   integer(Before), Before < 0 ->
+            domain_error(not_less_than_zero, Before, sub_string/5)
+        ;   integer(Length), Length < 0 ->
+            domain_error(not_less_than_zero, Length, sub_string/5)
+        ;   integer(After), After < 0 ->
+            domain_error(not_less_than_zero, After, sub_string/5)
+        ;   length(String, StringLength),
+            (   '$skip_max_list'(SubStringLength, _, SubString, Rs),
+                Rs == [] ->
+                Length = SubStringLength
+            ;   true
+            ),
+            sub_string_(String, StringLength, Before, Length, After, SubString)
+        ).
+
+sub_string_(String, StringLength, Before, Length, After, SubString) :-
+        (   integer(Before) ->
+            (   integer(Length) ->
+                true
+            ;   integer(After) ->
+                Length is StringLength - Before - After,
+                Length >= 0
+            ;   MaxLength is StringLength - Before,
+                between(0, MaxLength, Length)
+            ),
+            After is StringLength - Before - Length,
+            After >= 0,
+            string_suffix(Before, String, Suffix)
+        ;   integer(Length), integer(After) ->
+            Before is StringLength - Length - After,
+            Before >= 0,
+            string_suffix(Before, String, Suffix)
+        ;   (   integer(Length) ->
+                MaxBefore is StringLength - Length
+            ;   integer(After) ->
+                MaxBefore is StringLength - After
+            ;   MaxBefore = StringLength
+            ),
+            string_suffixes(String, 0, MaxBefore, Before, Suffix),
+            MaxLength is StringLength - Before,
+            (   integer(Length) ->
+                true
+            ;   integer(After) ->
+                Length is MaxLength - After
+            ;   between(0, MaxLength, Length)
+            ),
+            After is MaxLength - Length
+        ),
+        string_prefix(Length, Suffix, SubString).
+
+% Suffix is String without its first N characters.
+string_suffix(N, String, Suffix) :-
+        (   N =:= 0 ->
+            Suffix = String
+        ;   String = [_|String1],
+            N1 is N - 1,
+            string_suffix(N1, String1, Suffix)
+        ).
+
+% the suffixes of String from the one starting at B0 on to the one
+% starting at MaxB, and where they start.
+string_suffixes(String, B0, MaxB, B, Suffix) :-
+        (   B0 =:= MaxB ->
+            B = B0,
+            Suffix = String
+        ;   B0 < MaxB,
+            (   B = B0,
+                Suffix = String
+            ;   String = [_|String1],
+                B1 is B0 + 1,
+                string_suffixes(String1, B1, MaxB, B, Suffix)
+            )
+        ).
+
+% Prefix is the first N characters of String.
+string_prefix(N, String, Prefix) :-
+        (   N =:= 0 ->
+            Prefix = []
+        ;   String = [C|String1],
+            Prefix = [C|Prefix1],
+            N1 is N - 1,
+            string_prefix(N1, String1, Prefix1)
+        ).
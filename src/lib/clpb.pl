@@ -1732,7 +1732,6 @@ del_clpb(Var) :-
         del_attr(Var, clpb_atom).
 
 b_setval(K, T) :- bb_b_put(K, T).
-nb_setval(K, T) :- bb_put(K, T).
 b_getval(K, T) :- bb_get(K, T).
 
 /* - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
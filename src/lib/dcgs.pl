@@ -3,6 +3,12 @@
 A Prolog definite clause grammar (DCG) describes a sequence. Operationally, DCGs
 can be used to parse, generate, complete and check sequences manifested as lists.
 
+Grammar rules are translated to predicates with two additional arguments
+that form a difference list: the list being described and its remainder.
+Concatenating grammar bodies only threads these remainders through, so
+it takes constant time regardless of the length of the sequences, and
+no explicit `append/3` is needed to combine them.
+
 Check [The Power of Prolog chapter on DCGs](https://www.metalevel.at/prolog/dcg)
 to learn more about them.
 */
@@ -14,6 +20,9 @@ to learn more about them.
            phrase/3,
            phrase/4,
            phrase/5,
+           append_dl/3,
+           list_to_dlist/2,
+           dlist_to_list/2,
            seq//1,
            seqq//1,
            ... //0,
@@ -114,6 +123,51 @@ phrase(GRBody, Arg1, Arg2, S0, S) :-
     ;  call(M:GRBody1, Arg1, Arg2, S0, S)
     ).
 
+%% append_dl(?DL1, ?DL2, ?DL).
+%
+% DL is the concatenation of the difference lists DL1 and DL2. A
+% difference list `Ls-Ls0` stands for the elements of Ls up to its
+% tail Ls0, such as the two lists related by `phrase/3`. Like
+% concatenating two grammar bodies, this takes constant time.
+%
+% Example:
+%
+% ```
+% ?- append_dl([a,b|T1]-T1, [c|T2]-T2, DL).
+%    T1 = [c|T2], DL = [a,b,c|T2]-T2.
+% ```
+
+append_dl(Ls-Ls0, Ls0-Ls1, Ls-Ls1).
+
+%% list_to_dlist(?Ls, ?DL).
+%
+% DL is a difference list with the same elements as the list Ls,
+% and an unbound tail.
+%
+% Example:
+%
+% ```
+% ?- list_to_dlist("ab", DL).
+%    DL = [a,b|_A]-_A.
+% ```
+
+list_to_dlist(Ls, DLs-DLs0) :-
+    append(Ls, DLs0, DLs).
+
+%% dlist_to_list(+DL, -Ls).
+%
+% Ls is the list of elements of the difference list DL. The tail of
+% DL is closed with `[]`, so DL can't be extended afterwards.
+%
+% Example:
+%
+% ```
+% ?- phrase(("ab", "c"), Ls, Ls0), dlist_to_list(Ls-Ls0, Ls1).
+%    Ls = "abc", Ls0 = [], Ls1 = "abc".
+% ```
+
+dlist_to_list(Ls-[], Ls).
+
 % The same version of the below two dcg_rule clauses, but with module scoping.
 dcg_rule(( M:NonTerminal, Terminals --> GRBody ), ( M:Head :- Body )) :-
     dcg_non_terminal(NonTerminal, S0, S, Head),
@@ -102,17 +102,17 @@ cut(y(1)).
 put_unsafe_value(3,1).
 put_unsafe_value(2,2).
 deallocate.
-execute_default(is,2).
+execute_default(is,2,x(1),x(2)).
 default_retry_me_else(4).
 call(integer,1).
 neck_cut.
-execute(=:=,2).
+execute(=:=,2,x(1),x(2)).
 default_trust_me(0).
 allocate(2).
 get_variable(y(1),1).
 get_variable(y(2),3).
 put_value(y(2),1).
-call_default(is,2).
+call_default(is,2,x(1),x(2)).
 put_unsafe_value(2,1).
 put_unsafe_value(1,2).
 deallocate.
@@ -132,13 +132,13 @@ cut(y(1)).
 put_unsafe_value(3,1).
 put_unsafe_value(2,2).
 deallocate.
-execute_default(is,2).
+execute_default(is,2,x(1),x(2)).
 default_trust_me(0).
 allocate(2).
 get_variable(y(2),1).
 get_variable(y(1),3).
 put_value(y(1),1).
-call_default(is,2).
+call_default(is,2,x(1),x(2)).
 put_unsafe_value(2,1).
 put_unsafe_value(1,2).
 deallocate.
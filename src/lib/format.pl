@@ -18,7 +18,10 @@
                    portray_clause_//1,
                    portray_clause/1,
                    portray_clause/2,
-                   listing/1
+                   listing/0,
+                   listing/1,
+                   print/1,
+                   print/2
                   ]).
 
 :- use_module(library(dcgs)).
@@ -26,6 +29,7 @@
 :- use_module(library(error)).
 :- use_module(library(charsio)).
 :- use_module(library(between)).
+:- use_module(library(iso_ext)).
 :- use_module(library(pio)).
 
 %% format_(+FormatString, +Arguments)//
@@ -495,17 +499,47 @@ user:goal_expansion(format(Fs, Args),
                     (   current_output(Stream),
                         format(Stream, Fs, Args))).
 
-%% format(Stream, FormatString, Arguments)
+%% format(Sink, FormatString, Arguments)
 %
-%  Output the described string to the given Stream. If Stream is a
+%  Output the described string to the given Sink. If Sink is a
 %  binary stream, then the code of each emitted character must be in
 %  0..255.
+%
+%  Instead of a stream, Sink can also be one of `atom(A)`, `string(S)`,
+%  `chars(Cs)` or `codes(Cs)`, to unify A, S, Cs with the described
+%  string as an atom, string, list of characters or list of codes:
+%
+% ```
+% ?- format(atom(A), "~w-~w", [a,b]).
+%    A = 'a-b'.
+% ```
 
 format(_, _, _) :- not_used.
 
-user:goal_expansion(format(Stream, Fs, Args),
-                    (   pio:phrase_to_stream(format:format_(Fs, Args), Stream),
-                        flush_output(Stream))).
+user:goal_expansion(format(Sink, Fs, Args),
+                    format:format_to_sink(Sink, Fs, Args)).
+
+format_to_sink(Sink, _, _) :-
+        var(Sink),
+        !,
+        instantiation_error(format/3).
+format_to_sink(atom(A), Fs, Args) :-
+        !,
+        phrase(format_(Fs, Args), Cs),
+        atom_chars(A, Cs).
+format_to_sink(string(S), Fs, Args) :-
+        !,
+        phrase(format_(Fs, Args), S).
+format_to_sink(chars(Cs), Fs, Args) :-
+        !,
+        phrase(format_(Fs, Args), Cs).
+format_to_sink(codes(Cs), Fs, Args) :-
+        !,
+        phrase(format_(Fs, Args), Chars),
+        maplist(char_code, Chars, Cs).
+format_to_sink(Stream, Fs, Args) :-
+        pio:phrase_to_stream(format_(Fs, Args), Stream),
+        flush_output(Stream).
 
 /* - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
 ?- phrase(format:cells("hello", [], 0, [], []), Cs).
@@ -567,7 +601,8 @@ aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa
 - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
 
 /* - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
-   We also provide rudimentary versions of portray_clause/1 and listing/1.
+   We also provide rudimentary versions of portray_clause/1, listing/0
+   and listing/1.
 
    In the eventual library organization, portray_clause/1 and
    related predicates may be placed in their own dedicated library.
@@ -578,6 +613,17 @@ aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa
 %
 %  `portray_clause/1` is useful for printing solutions in such a way
 %  that they can be read back with `read/1`.
+%
+%  Variables are named `A`, `B`, ... and the clause is laid out as
+%  `listing/1` shows it: the head on its own line, then each body goal
+%  indented on a line of its own, and a terminating full stop.
+%
+%  ```
+%  ?- portray_clause((foo(X) :- bar(X), baz(X))).
+%  foo(A) :-
+%     bar(A),
+%     baz(A).
+%  ```
 
 portray_clause(Term) :-
         current_output(Out),
@@ -696,6 +742,15 @@ a :-
 
 - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
 
+%% listing(+PI)
+%
+%  Print the clauses of the predicate PI, given as `Name/Arity` or
+%  `Name//Arity`, in the format of `portray_clause/1`. The clauses of
+%  dynamic predicates are retrieved with `clause/2`. Static predicates
+%  of the user module are printed from the source terms the loader
+%  keeps alongside their compiled clauses. Other static predicates
+%  raise a permission error.
+
 listing(PI) :-
         nonvar(PI),
         (   PI = Name/Arity0 ->
@@ -705,12 +760,133 @@ listing(PI) :-
         ;   type_error(predicate_indicator, PI, listing/1)
         ),
         functor(Head, Name, Arity),
-        \+ \+ clause(Head, _), % only true if there is at least one clause
-        (   clause(Head, Body),
-            (   Body == true ->
-                portray_clause(Head)
-            ;   portray_clause((Head :- Body))
+        (   \+ predicate_property(user:Head, dynamic),
+            loader:listing_clause(Name, Arity, _, _) ->
+            (   loader:listing_clause(Name, Arity, _, Clause),
+                (   Clause = (Head0 :- Body),
+                    Body == true ->
+                    portray_clause(Head0)
+                ;   portray_clause(Clause)
+                ),
+                false
+            ;   true
+            )
+        ;   \+ \+ clause(Head, _), % only true if there is at least one clause
+            (   clause(Head, Body),
+                (   Body == true ->
+                    portray_clause(Head)
+                ;   portray_clause((Head :- Body))
+                ),
+                false
+            ;   true
+            )
+        ).
+
+%% listing
+%
+%  List every predicate defined in the user module with `listing/1`,
+%  static and dynamic alike, in the order in which the predicates were
+%  loaded, each followed by a blank line. Predicates without clauses
+%  are skipped, and so are builtins and library predicates unless the
+%  user module redefines them.
+
+listing :-
+        (   current_predicate(user:Name/Arity),
+            functor(Head, Name, Arity),
+            (   predicate_property(user:Head, dynamic) ->
+                \+ \+ clause(Head, _)
+            ;   \+ \+ loader:listing_clause(Name, Arity, _, _)
             ),
+            listing(Name/Arity),
+            nl,
             false
         ;   true
         ).
+
+%% print(+Term)
+%
+%  Like `print/2`, writing to the current output stream.
+
+print(Term) :-
+        current_output(Out),
+        print(Out, Term).
+
+%% print(+Stream, +Term)
+%
+%  Write Term to Stream like `writeq/2`, except that every subterm for
+%  which the user-defined hook `portray/1` succeeds is written by the
+%  hook instead, and its own subterms are not visited further. Terms
+%  are written as usual where `portray/1` fails or is not defined.
+%  Lists are offered to the hook as a whole and element by element,
+%  but not tail by tail.
+%
+%  ```
+%  ?- [user].
+%  portray(point(X, Y)) :- format("<~w,~w>", [X, Y]).
+%
+%  ?- print([point(1,2), p]).
+%  [<1,2>,p]   true.
+%  ```
+
+print(Stream, Term) :-
+        (   current_predicate(user:portray/1),
+            acyclic_term(Term) ->
+            phrase(portrayed(Term, Skeleton), VNs)
+        ;   Skeleton = Term,
+            VNs = []
+        ),
+        write_term(Stream, Skeleton, [quoted(true), numbervars(true),
+                                      variable_names(VNs)]).
+
+% Skeleton is Term with each portrayed subterm replaced by a fresh
+% variable, which is described as being named after the text that
+% portray/1 produced for it.
+portrayed(Term, Skeleton) -->
+        (   { var(Term) } ->
+            { Skeleton = Term }
+        ;   { portray_chars(Term, Cs) } ->
+            { atom_chars(Name, Cs) },
+            [Name=Skeleton]
+        ;   { atomic(Term) } ->
+            { Skeleton = Term }
+        ;   { Term = [L|Ls] } ->
+            { Skeleton = [S|Ss] },
+            portrayed(L, S),
+            portrayed_tail(Ls, Ss)
+        ;   { Term =.. [F|Args],
+              length(Args, N),
+              length(SArgs, N),
+              Skeleton =.. [F|SArgs] },
+            foldl(portrayed, Args, SArgs)
+        ).
+
+portrayed_tail(Ls, Ss) -->
+        (   { nonvar(Ls), Ls = [L|Ls1] } ->
+            { Ss = [S|Ss1] },
+            portrayed(L, S),
+            portrayed_tail(Ls1, Ss1)
+        ;   portrayed(Ls, Ss)
+        ).
+
+% Cs is the text that portray/1 writes for Term, if it succeeds.
+portray_chars(Term, Cs) :-
+        '$open_memory_stream'(S),
+        call_cleanup((   current_output(Out),
+                         setup_call_cleanup(set_output(S),
+                                            (   \+ \+ user:portray(Term) ->
+                                                Portrayed = true
+                                            ;   Portrayed = false
+                                            ),
+                                            set_output(Out)),
+                         stream_chars(S, Cs)
+                     ),
+                     close(S)),
+        Portrayed == true.
+
+stream_chars(S, Cs) :-
+        get_char(S, C),
+        (   C == end_of_file ->
+            Cs = []
+        ;   Cs = [C|Cs1],
+            stream_chars(S, Cs1)
+        ).
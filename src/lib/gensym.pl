@@ -15,6 +15,15 @@ append_id(Base, UniqueID, Unique) :-
     append(BaseChars, IDChars, AtomChars),
     atom_chars(Unique, AtomChars).
 
+%% gensym(+Base, -Unique).
+%
+% Unique is a fresh atom made of Base followed by the next value of a
+% counter kept for Base, starting at 1:
+%
+% ```
+% ?- gensym(node, X), gensym(node, Y).
+%    X = node1, Y = node2.
+% ```
 gensym(Base, Unique) :-
     must_be(var, Unique),
     atom_si(Base),
@@ -26,6 +35,10 @@ gensym(Base, Unique) :-
     append_id(Base, UniqueID, Unique),
     bb_put(BaseKey, UniqueID).
 
+%% reset_gensym(+Base).
+%
+% Reset the counter of Base, so that the next `gensym/2` call for Base
+% starts at 1 again.
 reset_gensym(Base) :-
     atom_si(Base),
     gensym_key(Base, BaseKey),
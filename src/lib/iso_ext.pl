@@ -4,11 +4,20 @@ Predicates available here are similar to the ones defined in builtin.pl,
 but they're not part of the ISO Prolog standard at the moment.
 */
 
-:- module(iso_ext, [bb_b_put/2,
+:- module(iso_ext, [(@)/2,
+                    (?=)/2,
+                    apply/2,
+                    assert/2,
+                    asserta/2,
+                    assertz/2,
+                    bb_b_put/2,
                     bb_get/2,
                     bb_put/2,
+                    nb_getval/2,
+                    nb_setval/2,
                     call_cleanup/2,
                     call_with_inference_limit/3,
+                    clause/3,
                     call_residue_vars/2,
                     forall/2,
                     partial_string/1,
@@ -18,15 +27,18 @@ but they're not part of the ISO Prolog standard at the moment.
                     succ/2,
                     call_nth/2,
                     countall/2,
+                    erase/1,
                     copy_term_nat/2,
 		    copy_term/3]).
 
 :- use_module(library(error), [can_be/2,
                                domain_error/3,
                                instantiation_error/1,
+                               must_be/2,
                                type_error/3]).
 
-:- use_module(library(lists), [maplist/3]).
+:- use_module(library(lists), [append/3, maplist/3, maplist/4, member/2]).
+:- use_module(library(loader), [strip_module/3]).
 
 :- use_module(library('$project_atts')).
 
@@ -45,6 +57,80 @@ but they're not part of the ISO Prolog standard at the moment.
 forall(Generate, Test) :-
     \+ (Generate, \+ Test).
 
+:- meta_predicate(apply(:, ?)).
+
+%% apply(Goal, ExtraArgs).
+%
+% Call Goal with the elements of the list ExtraArgs appended to its
+% arguments. This is the list-based counterpart of `call/N`, for when
+% the number of extra arguments is only known at runtime:
+%
+% ```
+% ?- apply(append([a]), [[b], Ls]).
+%    Ls = [a,b].
+% ```
+apply(Goal0, ExtraArgs) :-
+    must_be(list, ExtraArgs),
+    strip_module(Goal0, Module, Goal),
+    (  var(Goal) ->
+       instantiation_error(apply/2)
+    ;  callable(Goal) ->
+       true
+    ;  type_error(callable, Goal, apply/2)
+    ),
+    Goal =.. GoalList0,
+    append(GoalList0, ExtraArgs, GoalList),
+    Goal1 =.. GoalList,
+    call(Module:Goal1).
+
+:- meta_predicate(@(:, ?)).
+
+%% @(Goal, Module).
+%
+% Call Goal with Module as its context module. Goal itself is looked up
+% in the module it is qualified with, which is the module calling `@/2`
+% unless Goal is qualified explicitly, or in `user` if Goal comes
+% without a module qualification, as it does when `@/2` is called from
+% `user`. If Goal is a meta-predicate, its unqualified meta arguments
+% are resolved in Module rather than in the caller's module. This lets
+% a library call back into a module of its choosing. For instance,
+% `Goal` is called in `Module` by:
+%
+% ```
+% run_in(Module, Goal) :-
+%     @(maplist(call, [Goal]), Module).
+% ```
+@(Goal0, Module) :-
+    must_be(atom, Module),
+    strip_user_module(Goal0, GoalModule, Goal),
+    (  callable(Goal),
+       predicate_property(GoalModule:Goal, meta_predicate(MetaSpecs0)) ->
+       MetaSpecs0 =.. [_ | MetaSpecs],
+       Goal =.. [Name | Args0],
+       maplist(qualify_meta_arg(Module), MetaSpecs, Args0, Args),
+       Goal1 =.. [Name | Args]
+    ;  Goal1 = Goal
+    ),
+    call(GoalModule:Goal1).
+
+qualify_meta_arg(Module, MetaSpec, Arg0, Arg) :-
+    (  (  integer(MetaSpec)
+       ;  MetaSpec == (:)
+       ),
+       \+ ( nonvar(Arg0), Arg0 = _:_ ) ->
+       Arg = Module:Arg0
+    ;  Arg = Arg0
+    ).
+
+% like strip_module/3, but goals without a module qualification belong
+% to user.
+strip_user_module(Goal0, Module, Goal) :-
+    strip_module(Goal0, Module0, Goal),
+    (  var(Module0) ->
+       Module = user
+    ;  Module = Module0
+    ).
+
 % (non-)backtrackable global variables.
 
 %% bb_put(+Key, +Value).
@@ -114,6 +200,33 @@ bb_get(Key, Value) :-
     ;  type_error(atom, Key, bb_get/2)
     ).
 
+%% nb_setval(+Key, +Value).
+%
+% Like `bb_put/2`, but stores a copy of Value without attributes, so
+% the global variable never holds on to the constraints of the
+% variables in Value.
+%
+% ```
+% ?- dif(X, a), nb_setval(term, f(X)), nb_getval(term, T), T = f(a).
+%    T = f(a), dif:dif(X,a).
+% ```
+nb_setval(Key, Value) :-
+    (  atom(Key) ->
+       copy_term_nat(Value, Plain),
+       '$store_global_var'(Key, Plain)
+    ;  type_error(atom, Key, nb_setval/2)
+    ).
+
+%% nb_getval(+Key, -Value).
+%
+% Gets the value Value of a global variable named Key (must be an atom),
+% as `bb_get/2` does.
+nb_getval(Key, Value) :-
+    (  atom(Key) ->
+       '$fetch_global_var'(Key, Value)
+    ;  type_error(atom, Key, nb_getval/2)
+    ).
+
 
 %% succ(?I, ?S).
 %
@@ -132,6 +245,139 @@ succ(I, S) :-
     ).
 
 
+%% ?=(@X, @Y).
+%
+% True iff whether X and Y are equal is already decided: either they
+% are identical, or they cannot be unified. No bindings are made, so
+% `?=(X, a)` fails while X is unbound.
+?=(X, Y) :-
+    (   X == Y ->
+        true
+    ;   \+ X = Y
+    ).
+
+
+% clause references.
+
+:- meta_predicate(assert(:, ?)).
+
+%% assert(Clause, Ref).
+%
+% Same as `assertz/2`.
+assert(Clause, Ref) :-
+    assertz(Clause, Ref).
+
+:- meta_predicate(asserta(:, ?)).
+
+%% asserta(Clause, -Ref).
+%
+% Like `asserta/1`, but also unifies Ref with an opaque reference to
+% the new clause. The reference stays valid as other clauses are
+% asserted and retracted, and can be passed to `erase/1` and
+% `clause/3`.
+asserta(Clause0, Ref) :-
+    must_be(var, Ref),
+    strip_user_module(Clause0, Module, Clause),
+    asserta(Module:Clause),
+    clause_head(Clause, Head),
+    new_clause_ref(Module, Head, 0, Ref).
+
+:- meta_predicate(assertz(:, ?)).
+
+%% assertz(Clause, -Ref).
+%
+% Like `assertz/1`, but also unifies Ref with an opaque reference to
+% the new clause, as `asserta/2` does. This lets one of several
+% identical clauses be erased:
+%
+% ```
+% ?- assertz(p(1), _), assertz(p(1), Ref), erase(Ref), findall(X, p(X), Xs).
+%    Xs = [1].
+% ```
+assertz(Clause0, Ref) :-
+    must_be(var, Ref),
+    strip_user_module(Clause0, Module, Clause),
+    assertz(Module:Clause),
+    clause_head(Clause, Head),
+    new_clause_ref(Module, Head, last, Ref).
+
+clause_head((Head :- _), Head) :-
+    !.
+clause_head(Head, Head).
+
+% a clause is referred to by the location of its entry in '$clause'/2,
+% which is never reused, and so identifies the clause unlike its
+% position in the predicate. Pos is the position of the new clause,
+% which is either 0 or last.
+new_clause_ref(Module, Head, Pos, '$clause_ref'(Module, Name/Arity, L)) :-
+    '$get_clause_loc'(Head, Pos, Module, L),
+    functor(Head, Name, Arity).
+
+% the locations of the '$clause'/2 entries of the clauses of Head, in
+% order.
+clause_locs(Head, Module, N, Ls) :-
+    (  '$get_clause_loc'(Head, N, Module, L) ->
+       Ls = [L | Ls1],
+       N1 is N + 1,
+       clause_locs(Head, Module, N1, Ls1)
+    ;  Ls = []
+    ).
+
+% references are only checked to be well-formed. a well-formed
+% reference to no clause, such as one that was erased, makes its
+% caller fail.
+clause_ref_loc(Ref, PI, Module, Name/Arity, L) :-
+    (  var(Ref) ->
+       instantiation_error(PI)
+    ;  Ref = '$clause_ref'(Module, Name/Arity, L),
+       atom(Module),
+       atom(Name),
+       integer(Arity),
+       Arity >= 0,
+       integer(L),
+       L >= 0 ->
+       true
+    ;  type_error(db_reference, Ref, PI)
+    ).
+
+% find the clause of Head whose '$clause'/2 entry is at location L,
+% and its current position N in the predicate. fails if the clause
+% has been erased.
+referenced_clause(Module, Head, Body, L, N) :-
+    '$invoke_clause_at_loc'(Head, Body, L, N, Module).
+
+:- meta_predicate(clause(:, ?, ?)).
+
+%% clause(Head, Body, Ref).
+%
+% Like `clause/2`, but also relates each clause to its reference. If
+% Ref is bound, Head and Body are unified with the head and body of
+% the clause it refers to.
+clause(Head0, Body, Ref) :-
+    strip_user_module(Head0, Module0, Head),
+    (  nonvar(Ref) ->
+       clause_ref_loc(Ref, clause/3, Module, Name/Arity, L),
+       functor(Head, Name, Arity),
+       referenced_clause(Module, Head, Body, L, _)
+    ;  % raises the errors of clause/2.
+       \+ \+ clause(Module0:Head, Body),
+       clause_locs(Head, Module0, 0, Ls),
+       member(L, Ls),
+       referenced_clause(Module0, Head, Body, L, _),
+       functor(Head, Name, Arity),
+       Ref = '$clause_ref'(Module0, Name/Arity, L)
+    ).
+
+%% erase(+Ref).
+%
+% Retract the clause referred to by Ref, as obtained from `asserta/2`,
+% `assertz/2` or `clause/3`. Fails if the clause was already retracted.
+erase(Ref) :-
+    clause_ref_loc(Ref, erase/1, Module, Name/Arity, L),
+    functor(Head, Name, Arity),
+    referenced_clause(Module, Head, _, L, N),
+    '$retract_clause'(Name, Arity, N, Module).
+
 % setup_call_cleanup.
 
 :- meta_predicate(call_cleanup(0, 0)).
@@ -169,6 +169,13 @@ append([X|L], R, [X|S]) :- append(L, R, S).
 %% memberchk(?X, +Xs).
 %
 % This predicate is similar to `member/2`, but it only provides a single answer
+% and leaves no choice point: it commits to the first element of Xs that unifies
+% with X.
+%
+% ```
+% ?- memberchk(X-Y, [a-1,b-2,a-3]), X = a.
+%    X = a, Y = 1.
+% ```
 memberchk(X, Xs) :- member(X, Xs), !.
 
 %% reverse(?Xs, ?Ys).
@@ -0,0 +1,64 @@
+/** Memoization of ground goals
+
+`memo/1` remembers whether a ground goal succeeded, so calling the
+same goal again does not run it a second time. The cache is dropped
+as soon as any clause is asserted or retracted, which keeps cached
+answers consistent with dynamic predicates at the cost of also
+discarding answers that did not depend on them.
+*/
+
+:- module(memo, [memo/1,
+                 memo_clear/0]).
+
+:- use_module(library(assoc)).
+:- use_module(library(error)).
+:- use_module(library(iso_ext)).
+
+:- meta_predicate(memo(0)).
+
+% the cache is an assoc from goals to true or false, tagged with the
+% global clock it is valid for. the clock ticks on every assertion and
+% retraction.
+memo_cache(Clock, Cache) :-
+    (   bb_get('$memo_cache', Clock-Cache0) ->
+        Cache = Cache0
+    ;   empty_assoc(Cache)
+    ).
+
+%% memo(+Goal).
+%
+% Like `once(Goal)`, but the outcome is cached and later calls of an
+% identical Goal are answered from the cache until the database
+% changes. Goal must be ground.
+%
+% ```
+% ?- memo(atom_length(abc, 3)).
+%    true.
+% ```
+memo(Goal) :-
+    (   ground(Goal) ->
+        true
+    ;   instantiation_error(memo/1)
+    ),
+    '$get_global_clock'(Clock),
+    memo_cache(Clock, Cache0),
+    (   get_assoc(Goal, Cache0, Result) ->
+        true
+    ;   (   call(Goal) ->
+            Result = true
+        ;   Result = false
+        ),
+        '$get_global_clock'(Clock1),
+        memo_cache(Clock1, Cache1),
+        put_assoc(Goal, Cache1, Result, Cache),
+        bb_put('$memo_cache', Clock1-Cache)
+    ),
+    Result == true.
+
+%% memo_clear.
+%
+% Forget every cached outcome.
+memo_clear :-
+    '$get_global_clock'(Clock),
+    empty_assoc(Cache),
+    bb_put('$memo_cache', Clock-Cache).
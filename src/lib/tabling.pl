@@ -15,6 +15,21 @@
 :- table p/2.
 
 ...
+```
+
+    Instead of a predicate indicator, the directive also accepts a
+    head whose arguments are answer modes. An argument that is left
+    unbound indexes the answers, and for each combination of indexed
+    arguments only the answer with the greatest (`max`) or least
+    (`min`) value of the remaining arguments is kept, in the standard
+    order of terms. For example, shortest distances in a weighted
+    graph, which may contain cycles:
+
+```
+:- table path(_,_,min).
+
+path(X, Y, D) :- edge(X, Y, D).
+path(X, Z, D) :- path(X, Y, D0), edge(Y, Z, D1), D is D0+D1.
 ```
 
     The possibility to apply different execution strategies is one of
@@ -168,7 +183,7 @@ delim(Wrapper,Worker,Table) :-
           _,
           fail),
    ( Continuation = none ->
-     (	 add_answer(Table,Wrapper)
+     (	 add_table_answer(Table,Wrapper)
      ->	 true %debug(tabling, 'ADD: ~p', [Wrapper])
      ;	 %debug(tabling, 'DUP: ~p', [Wrapper]),
 	 fail
@@ -182,6 +197,18 @@ delim(Wrapper,Worker,Table) :-
      store_dependency(SourceTable,Dependency)
    ).
 
+% Tables declared with answer modes, such as `:- table path(_,_,min).`,
+% keep only the best answer per combination of indexed arguments.
+add_table_answer(Table,Wrapper) :-
+  Wrapper = Module:Head,
+  functor(Head,Name,Arity),
+  functor(Template,Name,Arity),
+  ( table_wrapper:answer_modes(Template,Module,Modes) ->
+    add_moded_answer(Table,Wrapper,Modes)
+  ;
+    add_answer(Table,Wrapper)
+  ).
+
 completion :-
   ( worklist_empty ->
     set_all_complete,
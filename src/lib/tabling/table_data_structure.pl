@@ -1,6 +1,7 @@
 :- module(table_datastructure,
 	  [ get_answer/2,			% +TableID, -Answer
 	    add_answer/2,			% +TableID, +Answer
+	    add_moded_answer/3,			% +TableID, +Answer, +Modes
 	    get_call_variant/2,			% +TableID, -CallVariant
 	    set_complete_status/1,		% +TableID
 	    set_active_status/1,		% +TableID
@@ -181,6 +182,56 @@ add_answer(TableIdentifier,A) :-
   bb_put(NbWorklistID, table_nb_worklist(Worklist)),
   bb_put(NbAnswerTrieID, table_nb_answer_trie(AnswerTrie)).
 
+% Like add_answer/2, but answers that agree on the arguments in index
+% mode share a single entry of the answer trie, which holds the best
+% answer found so far according to Modes. Fails unless A improves on it.
+add_moded_answer(TableIdentifier,A,Modes) :-
+  p_get_table_for_identifier(TableIdentifier,Table),
+  arg(3,Table,NbWorklistID),
+  arg(4,Table,NbAnswerTrieID),
+  bb_get(NbWorklistID,table_nb_worklist(Worklist)),
+  bb_get(NbAnswerTrieID,table_nb_answer_trie(AnswerTrie)),
+  copy_term(A,A2),
+  A2 = _:Head,
+  Head =.. [_|Args],
+  moded_args(Modes,Args,Key,Aggregates),
+  ( trie_lookup(AnswerTrie,Key,Old) ->
+    Old = _:OldHead,
+    OldHead =.. [_|OldArgs],
+    moded_args(Modes,OldArgs,_,OldAggregates),
+    better_answer(Aggregates,OldAggregates),
+    trie_replace(AnswerTrie,Key,A2)
+  ;
+    trie_insert(AnswerTrie,Key,A2)
+  ),
+  wkl_add_answer(Worklist,A2),
+  bb_put(NbWorklistID, table_nb_worklist(Worklist)),
+  bb_put(NbAnswerTrieID, table_nb_answer_trie(AnswerTrie)).
+
+% Splits the arguments of an answer into the indexed ones, which form
+% the key of the answer trie, and Mode-Value pairs for the others.
+moded_args([],[],[],[]).
+moded_args([Mode|Modes],[Arg|Args],Key,Aggregates) :-
+  ( Mode == index ->
+    Key = [Arg|Key1],
+    Aggregates = Aggregates1
+  ;
+    Key = Key1,
+    Aggregates = [Mode-Arg|Aggregates1]
+  ),
+  moded_args(Modes,Args,Key1,Aggregates1).
+
+% The first aggregated argument that differs decides.
+better_answer([Mode-New|News],[_-Old|Olds]) :-
+  compare(Order,New,Old),
+  ( Order == (=) ->
+    better_answer(News,Olds)
+  ; Mode == max ->
+    Order == (>)
+  ;
+    Order == (<)
+  ).
+
 get_answer(TableIdentifier,A) :-
   p_get_table_for_identifier(TableIdentifier,Table),
   get_answer_trie_(Table,AnswerTrie),
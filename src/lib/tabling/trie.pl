@@ -38,6 +38,7 @@
 	    trie_insert/3,			% !Trie, +Key, +Value
 	    trie_insert_succeed/3,
 	    trie_lookup/3,			% +Trie, +Key, -Value
+	    trie_replace/3,			% !Trie, +Key, +Value
 	    trie_get_all_values/2		% +Trie, -Value
 	  ]).
 
@@ -203,26 +204,34 @@ trie_insert_2(RegularTerm,Rest,Trie,Value) :-
 
 trie_lookup(Trie,Key,Value) :-
   p_trie_arity_univ(Key,FunctorData,KeyList),
-  trie_lookup_1(FunctorData,KeyList,Trie,Value).
+  trie_node_1(FunctorData,KeyList,Trie,Node),
+  % If the value at this trie is maybe_just(X), then X is our Value.
+  % Otherwise, there is no value for this key, so we fail...
+  trie_get_maybe_value(Node,Value).
+
+% Replaces the value stored for a key that is already present, fails otherwise.
+trie_replace(Trie,Key,Value) :-
+  p_trie_arity_univ(Key,FunctorData,KeyList),
+  trie_node_1(FunctorData,KeyList,Trie,Node),
+  trie_get_maybe_value(Node,_),
+  trie_set_maybe_value(Node,maybe_just(Value)).
 
-trie_lookup_1(FunctorData,Rest,Trie,Value) :-
+% Finds the subtrie for a key, whether or not it holds a value.
+trie_node_1(FunctorData,Rest,Trie,Node) :-
   % Select right subtree, fail if it isn't there, and do recursive call.
   trie_get_children(Trie,Assoc),
   get_assoc(FunctorData,Assoc,Subtrie), % Fails if not present
-  trie_lookup_2(Rest,Subtrie,Value).
+  trie_node_2(Rest,Subtrie,Node).
 
-trie_lookup_2([],Trie,Value) :-
-  % If the value at this trie is maybe_just(X), then X is our Value.
-  % Otherwise, there is no value for this key, so we fail...
-  trie_get_maybe_value(Trie,Value).
+trie_node_2([],Trie,Trie).
 % Regular term at the head, like p or p(a). Not functor_data/2.
-trie_lookup_2([RegularTerm|Rest],Trie,Value) :-
+trie_node_2([RegularTerm|Rest],Trie,Node) :-
   % split RegularTerm
   p_trie_arity_univ(RegularTerm,FunctorData,KList),
   % Make a recursive call on KList ++ Rest.
   % Since we cannot implement p_trie_arity_univ so that "its result", KList, has a free variable at the end, without resorting to techniques that require linear time, we need a call to append/3. However, since KList will in general be rather short, I don't expect this to be a large problem in practice.
   append(KList,Rest,KList2),
-  trie_lookup_1(FunctorData,KList2,Trie,Value).
+  trie_node_1(FunctorData,KList2,Trie,Node).
 
 
 % Returns all values in the trie by backtracking - we don't provide any information about the associated key.
@@ -41,6 +41,7 @@
 :- use_module(library(error)).
 
 :- multifile(tabled/2).
+:- multifile(answer_modes/3).
 
 %%:- multifile
 %%	system:term_expansion/2,
@@ -85,6 +86,39 @@ wrappers(Name/Arity) -->
 	  (:- multifile(table_wrapper:tabled/2)),
 	  table_wrapper:tabled(Head, Module)
 	].
+wrappers(ModedHead) -->
+	{ compound(ModedHead),
+	  ModedHead \= _/_,
+	  ModedHead \= _//_, !,
+	  ModedHead =.. [Name|ModeArgs],
+	  answer_modes(ModeArgs, Modes),
+	  functor(ModedHead, Name, Arity),
+	  functor(Head, Name, Arity),
+	  prolog_load_context(module, Module)
+	},
+	wrappers(Name/Arity),
+	[ (:- multifile(table_wrapper:answer_modes/3)),
+	  table_wrapper:answer_modes(Head, Module, Modes)
+	].
+
+% An argument left unbound indexes the answers; max and min keep only
+% the greatest or least value of their argument, in the standard order
+% of terms, among answers that agree on the indexed arguments.
+answer_modes([], []).
+answer_modes([Arg|Args], [Mode|Modes]) :-
+	(   var(Arg) ->
+	    Mode = index
+	;   answer_mode(Arg, Mode0) ->
+	    Mode = Mode0
+	;   domain_error(table_mode, Arg, (table)/1)
+	),
+	answer_modes(Args, Modes).
+
+answer_mode(index, index).
+answer_mode(max, max).
+answer_mode(max(_), max).
+answer_mode(min, min).
+answer_mode(min(_), min).
 
 rename(M:Term0, M:Term, _) :-
 	atom(M), !,
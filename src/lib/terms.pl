@@ -1,4 +1,4 @@
-:- module(terms, [numbervars/3]).
+:- module(terms, [numbervars/3, subsumes/2]).
 
 :- use_module(library(error)).
 
@@ -17,3 +17,19 @@ numberlist([], N, N).
 numberlist(['$VAR'(N0)|Vars], N0, N) :-
    N1 is N0+1,
    numberlist(Vars, N1, N).
+
+%% subsumes(?General, ?Specific)
+%
+% Like `subsumes_term/2`, but on success General is also unified with
+% Specific, so the bindings that make it an instance of General are
+% kept. The variables of Specific are left unbound.
+%
+% ```
+% ?- subsumes(f(X, Y), f(a, Z)).
+%    X = a, Y = Z.
+% ?- subsumes(f(a), f(X)).
+%    false.
+% ```
+subsumes(General, Specific) :-
+   subsumes_term(General, Specific),
+   General = Specific.
@@ -0,0 +1,89 @@
+/** Conservative partial evaluation of list predicates
+
+Loading this library unfolds calls to `library(lists)` whose outcome
+only depends on arguments already known when the clause is compiled:
+
+ * `member(X, Es)` and `memberchk(X, Es)`, where Es is a ground list,
+   become disjunctions and if-then-else chains of `X = E`;
+ * `append(Xs, Ys, Zs)`, where Xs is a proper list, becomes
+   `Zs = [X1, ..., Xn|Ys]`.
+
+The unfolded goals have the same solutions in the same order, and
+neither kind of goal can raise an error, so nothing else changes. Only
+goals that are qualified with `lists` or that refer to the predicates
+the module being loaded imports from `library(lists)` are unfolded, so
+a module defining its own `member/2` keeps calling it.
+*/
+
+:- module(unfold, [unfold_goal/2]).
+
+:- use_module(library(lists)).
+:- use_module(library(loader), [prolog_load_context/2]).
+
+% unlike a plain traversal, this does not bind the tail of a partial
+% list.
+proper_list(Es) :-
+    (   var(Es) ->
+        false
+    ;   Es == [] ->
+        true
+    ;   Es = [_|Es1],
+        proper_list(Es1)
+    ).
+
+member_disjunction([], _, false).
+member_disjunction([E], X, X = E) :-
+    !.
+member_disjunction([E|Es], X, (X = E ; Disjunction)) :-
+    member_disjunction(Es, X, Disjunction).
+
+memberchk_chain([], _, false).
+memberchk_chain([E], X, X = E) :-
+    !.
+memberchk_chain([E|Es], X, (X = E -> true ; Chain)) :-
+    memberchk_chain(Es, X, Chain).
+
+%% unfold_goal(+Goal, -Unfolded).
+%
+% Unfolded is a goal equivalent to Goal with the call to `member/2`,
+% `memberchk/2` or `append/3` evaluated away. Fails if Goal cannot be
+% unfolded.
+%
+% ```
+% ?- unfold_goal(member(X, [a,b,c]), G).
+%    G = (X=a;X=b;X=c).
+% ```
+unfold_goal(Goal, _) :-
+    var(Goal),
+    !,
+    false.
+unfold_goal(lists:Goal, Unfolded) :-
+    !,
+    unfold_goal(Goal, Unfolded).
+unfold_goal(member(X, Es), Unfolded) :-
+    ground(Es),
+    proper_list(Es),
+    member_disjunction(Es, X, Unfolded).
+unfold_goal(memberchk(X, Es), Unfolded) :-
+    ground(Es),
+    proper_list(Es),
+    memberchk_chain(Es, X, Unfolded).
+unfold_goal(append(Xs, Ys, Zs), Zs = Ws) :-
+    proper_list(Xs),
+    append(Xs, Ys, Ws).
+
+% Goal calls library(lists) from the module being loaded.
+lists_goal(Goal) :-
+    (   Goal = lists:_ ->
+        true
+    ;   (   prolog_load_context(module, Module) ->
+            true
+        ;   Module = user
+        ),
+        predicate_property(Module:Goal, imported_from(lists))
+    ).
+
+user:goal_expansion(Goal, Unfolded) :-
+    callable(Goal),
+    lists_goal(Goal),
+    unfold_goal(Goal, Unfolded).
@@ -6,6 +6,7 @@ Provides the predicate `when/2`.
 
 :- use_module(library(atts)).
 :- use_module(library(dcgs)).
+:- use_module(library(iso_ext), [(?=)/2]).
 :- use_module(library(lists)).
 :- use_module(library(lambda)).
 
@@ -18,7 +19,8 @@ Provides the predicate `when/2`.
 
 %% when(Condition, Goal).
 %
-% Executes Goal when Condition becomes true.
+% Executes Goal when Condition becomes true. Condition is built from
+% `nonvar(X)`, `ground(X)` and `?=(X, Y)` using `(,)/2` and `(;)/2`.
 when(Condition, Goal) :-
     (   when_condition(Condition) ->
         (   Condition ->
@@ -42,6 +44,7 @@ when_condition(Cond) :-
     var(Cond), !, throw(error(instantiation_error,when_condition/1)).
 when_condition(ground(_)).
 when_condition(nonvar(_)).
+when_condition(?=(_, _)).
 when_condition((A, B)) :-
     when_condition(A),
     when_condition(B).
@@ -49,6 +52,19 @@ when_condition((A ; B)) :-
     when_condition(A),
     when_condition(B).
 
+% ?=(X, Y) can be decided by aliasing X and Y to each other, while
+% ground/1 and nonvar/1 wait for a variable to be bound to a
+% non-variable.
+aliasing_condition(?=(_, _)).
+aliasing_condition((A, B)) :-
+    (   aliasing_condition(A)
+    ;   aliasing_condition(B)
+    ).
+aliasing_condition((A ; B)) :-
+    (   aliasing_condition(A)
+    ;   aliasing_condition(B)
+    ).
+
 remove_goal([], _, []).
 remove_goal([G0|G0s], Goal, Goals) :-
     (   G0 == Goal ->
@@ -80,7 +96,9 @@ reinforce_goal(Goal0, Goal) :-
 
 verify_attributes(Var, Value, Goals) :-
     (   get_atts(Var, when_list(Whens)) ->
-        (   var(Value) ->
+        (   var(Value),
+            \+ ( member(when(Condition, _), Whens),
+                 aliasing_condition(Condition) ) ->
             (   get_atts(Value, when_list(WhensValue)) ->
                 append(Whens, WhensValue, WhensNew),
                 put_atts(Value, when_list(WhensNew))
@@ -15,6 +15,12 @@
 :- use_module(library(lists)).
 :- use_module(library(pairs)).
 
+%% The clauses of static user predicates, kept in source form for
+%% listing/1, since compiled clauses can't be read back by clause/2.
+%% listing_batch/2 holds the predicate whose clauses are being queued.
+:- dynamic(listing_clause/4).
+:- dynamic(listing_batch/2).
+
 write_error(Error) :-
     % '$fetch_global_var' is the core system call of bb_get/2, but
     % bb_get may not exist when write_error is first called, so fall
@@ -151,6 +157,7 @@ file_load(Stream, Path, Evacuable) :-
     % local predicate clauses, etc. from a previous load of the file
     % at Path.
     '$add_in_situ_filename_module'(Evacuable),
+    forget_listing_clauses,
     catch(loader:file_load_init(Stream, Evacuable),
           E,
           loader:file_load_cleanup(Evacuable, E)),
@@ -340,7 +347,7 @@ compile_dispatch_or_clause(OrigTerm, Term, Evacuable) :-
     (  var(Term) ->
        instantiation_error(load/1)
     ;  compile_dispatch(Term, Evacuable) ->
-       '$flush_term_queue'(Evacuable)
+       flush_term_queue(Evacuable)
     ;  compile_clause(OrigTerm, Term, Evacuable)
     ).
 
@@ -479,16 +486,18 @@ compile_clause(OrigTerm, (Target:Head :- Body), Evacuable) :-
     !,
     functor(Head, Name, Arity),
     (  '$is_consistent_with_term_queue'(Target, Name, Arity, Evacuable) ->
-       '$scoped_clause_to_evacuable'(Target, (Head :- Body), Evacuable)
-    ;  '$flush_term_queue'(Evacuable),
+       '$scoped_clause_to_evacuable'(Target, (Head :- Body), Evacuable),
+       record_listing_clause(Target, Name, Arity, (Head :- Body))
+    ;  flush_term_queue(Evacuable),
        recompile_term(OrigTerm, (Target:Head :- Body), Evacuable)
     ).
 compile_clause(OrigTerm, Target:Head, Evacuable) :-
     !,
     functor(Head, Name, Arity),
     (  '$is_consistent_with_term_queue'(Target, Name, Arity, Evacuable) ->
-       '$scoped_clause_to_evacuable'(Target, Head, Evacuable)
-    ;  '$flush_term_queue'(Evacuable),
+       '$scoped_clause_to_evacuable'(Target, Head, Evacuable),
+       record_listing_clause(Target, Name, Arity, Head)
+    ;  flush_term_queue(Evacuable),
        recompile_term(OrigTerm, Target:Head, Evacuable)
     ).
 compile_clause(OrigTerm, (Head :- Body), Evacuable) :-
@@ -496,19 +505,60 @@ compile_clause(OrigTerm, (Head :- Body), Evacuable) :-
     prolog_load_context(module, Target),
     functor(Head, Name, Arity),
     (  '$is_consistent_with_term_queue'(Target, Name, Arity, Evacuable) ->
-       '$clause_to_evacuable'((Head :- Body), Evacuable)
-    ;  '$flush_term_queue'(Evacuable),
+       '$clause_to_evacuable'((Head :- Body), Evacuable),
+       record_listing_clause(Target, Name, Arity, (Head :- Body))
+    ;  flush_term_queue(Evacuable),
        recompile_term(OrigTerm, (Head :- Body), Evacuable)
     ).
 compile_clause(OrigTerm, Head, Evacuable) :-
     prolog_load_context(module, Target),
     functor(Head, Name, Arity),
     (  '$is_consistent_with_term_queue'(Target, Name, Arity, Evacuable) ->
-       '$clause_to_evacuable'(Head, Evacuable)
-    ;  '$flush_term_queue'(Evacuable),
+       '$clause_to_evacuable'(Head, Evacuable),
+       record_listing_clause(Target, Name, Arity, Head)
+    ;  flush_term_queue(Evacuable),
        recompile_term(OrigTerm, Head, Evacuable)
     ).
 
+flush_term_queue(Evacuable) :-
+    '$flush_term_queue'(Evacuable),
+    retractall(loader:listing_batch(_, _)).
+
+%% record_listing_clause(+Module, +Name, +Arity, +Clause)
+%
+% Keep Clause for listing/1 if it belongs to a static predicate of the
+% user module. A batch of queued clauses replaces the clauses of a
+% predicate that is neither multifile nor discontiguous, and its
+% source form must be replaced along with them.
+record_listing_clause(user, Name, Arity, Clause) :-
+    \+ '$dynamic_property'(user, Name, Arity),
+    !,
+    (  prolog_load_context(source, Source) ->
+       true
+    ;  Source = user
+    ),
+    (  loader:listing_batch(Name, Arity) ->
+       true
+    ;  retractall(loader:listing_batch(_, _)),
+       assertz(loader:listing_batch(Name, Arity)),
+       (  '$multifile_property'(user, Name, Arity) ->
+          true
+       ;  '$discontiguous_property'(user, Name, Arity) ->
+          true
+       ;  retractall(loader:listing_clause(Name, Arity, _, _))
+       )
+    ),
+    assertz(loader:listing_clause(Name, Arity, Source, Clause)).
+record_listing_clause(_, _, _, _).
+
+%% The clauses kept from a previous load of the file being loaded are
+%% dropped along with the predicates '$add_in_situ_filename_module'
+%% removes.
+forget_listing_clauses :-
+    prolog_load_context(source, Source),
+    retractall(loader:listing_clause(_, _, Source, _)),
+    retractall(loader:listing_batch(_, _)).
+
 
 prolog_load_context(source, Source) :-
     %% The absolute path name of the file being compiled. During
@@ -648,6 +698,8 @@ check_predicate_property(multifile, Module, Name, Arity, multifile) :-
     '$multifile_property'(Module, Name, Arity).
 check_predicate_property(discontiguous, Module, Name, Arity, discontiguous) :-
     '$discontiguous_property'(Module, Name, Arity).
+check_predicate_property(imported_from, Module, Name, Arity, imported_from(From)) :-
+    '$imported_from_property'(Module, Name, Arity, From).
 
 
 :- non_counted_backtracking extract_predicate_property/2.
@@ -749,7 +801,8 @@ expand_subgoal(UnexpandedGoals, MS, M, ExpandedGoals, HeadVars, TGs) :-
        )
     ),
     strip_subst_module(UnexpandedGoals3, Module, Module1, UnexpandedGoals4),
-    (  inner_meta_specs(0, UnexpandedGoals4, _, MetaSpecs) ->
+    (  predicate_property(Module1:UnexpandedGoals4, meta_predicate(MetaSpecs0)),
+       MetaSpecs0 =.. [_ | MetaSpecs] ->
        expand_module_names(UnexpandedGoals4, MetaSpecs, Module1, ExpandedGoals0, HeadVars, TGs)
     ;  ExpandedGoals0 = UnexpandedGoals4
     ),
@@ -1036,6 +1036,28 @@ pub(crate) fn float_integer_part(n1: Number) -> Result<f64, MachineStubGen> {
     unary_float_fn_template(n1, |f| f.trunc())
 }
 
+// the rational number exactly equal to n1.
+pub(crate) fn rational(n1: Number, arena: &mut Arena) -> Result<Number, MachineStubGen> {
+    match n1 {
+        Number::Float(OrderedFloat(f)) => match Rational::try_from(f) {
+            Ok(r) => Ok(Number::arena_from(r, arena)),
+            Err(_) => Err(undefined_eval_error(|| functor_stub(atom!("rational"), 1))),
+        },
+        n1 => Ok(n1),
+    }
+}
+
+// the simplest rational number that rounds to n1 as a float.
+pub(crate) fn rationalize(n1: Number, arena: &mut Arena) -> Result<Number, MachineStubGen> {
+    match n1 {
+        Number::Float(OrderedFloat(f)) => match Rational::simplest_from_f64(f) {
+            Some(r) => Ok(Number::arena_from(r, arena)),
+            None => Err(undefined_eval_error(|| functor_stub(atom!("rationalize"), 1))),
+        },
+        n1 => Ok(n1),
+    }
+}
+
 #[inline]
 pub(crate) fn sqrt(n1: Number) -> Result<f64, MachineStubGen> {
     if n1.is_negative() {
@@ -1315,6 +1337,12 @@ pub(crate) fn arith_eval_by_metacall(
                                 drop_iter_on_err!(self, iter, atan(a1))
                             ))),
                             atom!("abs") => self.interms.push(abs(a1, &mut self.arena)),
+                            atom!("rational") => self.interms.push(
+                                drop_iter_on_err!(self, iter, rational(a1, &mut self.arena))
+                            ),
+                            atom!("rationalize") => self.interms.push(
+                                drop_iter_on_err!(self, iter, rationalize(a1, &mut self.arena))
+                            ),
                             atom!("float") => self.interms.push(Number::Float(OrderedFloat(
                                 drop_iter_on_err!(self, iter, float(a1))
                             ))),
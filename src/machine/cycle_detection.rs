@@ -380,6 +380,21 @@ fn backward(&mut self) -> bool {
             self.heap[self.current].set_value(self.next);
             self.next = self.current as u64;
             self.current = temp as usize;
+
+            // partial string locations were forwarded through the
+            // tail cell following the PStr cell they point to, so
+            // restore them to point at the PStr cell itself.
+            match self.heap[self.current].get_tag() {
+                HeapCellValueTag::PStrLoc => {
+                    self.next -= 1;
+                }
+                HeapCellValueTag::PStrOffset
+                    if self.heap[self.next as usize - 1].get_tag() == HeapCellValueTag::PStr =>
+                {
+                    self.next -= 1;
+                }
+                _ => {}
+            }
         }
 
         if self.current == self.start {
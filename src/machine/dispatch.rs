@@ -980,6 +980,26 @@ pub(super) fn dispatch_loop(&mut self) -> std::process::ExitCode {
 
                         self.machine_st.p += 1;
                     }
+                    &Instruction::Rational(ref a1, t) => {
+                        let n1 = try_or_throw!(self.machine_st, self.machine_st.get_number(a1));
+
+                        self.machine_st.interms[t - 1] = try_or_throw_gen!(
+                            &mut self.machine_st,
+                            rational(n1, &mut self.machine_st.arena)
+                        );
+
+                        self.machine_st.p += 1;
+                    }
+                    &Instruction::Rationalize(ref a1, t) => {
+                        let n1 = try_or_throw!(self.machine_st, self.machine_st.get_number(a1));
+
+                        self.machine_st.interms[t - 1] = try_or_throw_gen!(
+                            &mut self.machine_st,
+                            rationalize(n1, &mut self.machine_st.arena)
+                        );
+
+                        self.machine_st.p += 1;
+                    }
                     &Instruction::Plus(ref a1, t) => {
                         let n1 = try_or_throw!(self.machine_st, self.machine_st.get_number(a1));
 
@@ -2884,6 +2904,14 @@ pub(super) fn dispatch_loop(&mut self) -> std::process::ExitCode {
                                             Ref::heap_cell(h),
                                             value,
                                         );
+
+                                        // the binding fails if the occurs
+                                        // check is enabled and h occurs in
+                                        // value.
+                                        if self.machine_st.fail {
+                                            self.machine_st.backtrack();
+                                            continue;
+                                        }
                                     }
                                 );
                             }
@@ -2933,6 +2961,11 @@ pub(super) fn dispatch_loop(&mut self) -> std::process::ExitCode {
                                     addr,
                                 );
 
+                                if self.machine_st.fail {
+                                    self.machine_st.backtrack();
+                                    continue;
+                                }
+
                                 // the former code of this match arm was:
 
                                 // let addr = self.machine_st.store(self.machine_st[reg]);
@@ -3776,6 +3809,14 @@ pub(super) fn dispatch_loop(&mut self) -> std::process::ExitCode {
                         try_or_throw!(self.machine_st, self.open());
                         step_or_fail!(self, self.machine_st.p = self.machine_st.cp);
                     }
+                    &Instruction::CallOpenMemoryStream => {
+                        try_or_throw!(self.machine_st, self.open_memory_stream());
+                        self.machine_st.p += 1;
+                    }
+                    &Instruction::ExecuteOpenMemoryStream => {
+                        try_or_throw!(self.machine_st, self.open_memory_stream());
+                        self.machine_st.p = self.machine_st.cp;
+                    }
                     &Instruction::CallSetStreamOptions => {
                         try_or_throw!(self.machine_st, self.set_stream_options());
                         self.machine_st.p += 1;
@@ -4029,6 +4070,14 @@ pub(super) fn dispatch_loop(&mut self) -> std::process::ExitCode {
                         self.get_current_scc_block();
                         step_or_fail!(self, self.machine_st.p = self.machine_st.cp);
                     }
+                    &Instruction::CallGetGlobalClock => {
+                        self.get_global_clock();
+                        step_or_fail!(self, self.machine_st.p += 1);
+                    }
+                    &Instruction::ExecuteGetGlobalClock => {
+                        self.get_global_clock();
+                        step_or_fail!(self, self.machine_st.p = self.machine_st.cp);
+                    }
                     &Instruction::CallGetCutPoint => {
                         self.get_cut_point();
                         step_or_fail!(self, self.machine_st.p += 1);
@@ -4205,6 +4254,14 @@ pub(super) fn dispatch_loop(&mut self) -> std::process::ExitCode {
                         try_or_throw!(self.machine_st, self.read_term_from_chars());
                         step_or_fail!(self, self.machine_st.p = self.machine_st.cp);
                     }
+                    &Instruction::CallReadTokensFromChars => {
+                        try_or_throw!(self.machine_st, self.read_tokens_from_chars());
+                        step_or_fail!(self, self.machine_st.p += 1);
+                    }
+                    &Instruction::ExecuteReadTokensFromChars => {
+                        try_or_throw!(self.machine_st, self.read_tokens_from_chars());
+                        step_or_fail!(self, self.machine_st.p = self.machine_st.cp);
+                    }
                     &Instruction::CallResetBlock => {
                         self.reset_block();
                         step_or_fail!(self, self.machine_st.p += 1);
@@ -4911,6 +4968,14 @@ pub(super) fn dispatch_loop(&mut self) -> std::process::ExitCode {
                         self.dynamic_property();
                         step_or_fail!(self, self.machine_st.p = self.machine_st.cp);
                     }
+                    &Instruction::CallImportedFromProperty => {
+                        self.imported_from_property();
+                        step_or_fail!(self, self.machine_st.p += 1);
+                    }
+                    &Instruction::ExecuteImportedFromProperty => {
+                        self.imported_from_property();
+                        step_or_fail!(self, self.machine_st.p = self.machine_st.cp);
+                    }
                     &Instruction::CallAbolishClause => {
                         try_or_throw!(self.machine_st, self.abolish_clause());
                         self.machine_st.p += 1;
@@ -5083,6 +5148,14 @@ pub(super) fn dispatch_loop(&mut self) -> std::process::ExitCode {
 
                         step_or_fail!(self, self.machine_st.p = self.machine_st.cp);
                     }
+                    &Instruction::CallGetClauseLoc => {
+                        self.get_clause_loc();
+                        step_or_fail!(self, self.machine_st.p += 1);
+                    }
+                    &Instruction::ExecuteGetClauseLoc => {
+                        self.get_clause_loc();
+                        step_or_fail!(self, self.machine_st.p = self.machine_st.cp);
+                    }
                     &Instruction::CallInvokeClauseAtP => {
                         let key_cell = self.machine_st.registers[1];
                         let key = self.machine_st.name_and_arity_from_heap(key_cell).unwrap();
@@ -5169,6 +5242,14 @@ pub(super) fn dispatch_loop(&mut self) -> std::process::ExitCode {
 
                         self.machine_st.execute_at_index(2, p);
                     }
+                    &Instruction::CallInvokeClauseAtLoc => match self.clause_at_loc() {
+                        Some(p) => self.machine_st.call_at_index(2, p),
+                        None => self.machine_st.backtrack(),
+                    },
+                    &Instruction::ExecuteInvokeClauseAtLoc => match self.clause_at_loc() {
+                        Some(p) => self.machine_st.execute_at_index(2, p),
+                        None => self.machine_st.backtrack(),
+                    },
                     &Instruction::CallGetFromAttributedVarList => {
                         self.get_from_attributed_variable_list();
                         step_or_fail!(self, self.machine_st.p += 1);
@@ -3,12 +3,13 @@
 
 use crate::atom_table;
 use crate::heap_print::{HCPrinter, HCValueOutputter, PrinterOutputter};
-use crate::machine::machine_indices::VarKey;
+use crate::instructions::ClauseType;
+use crate::machine::machine_indices::{IndexPtrTag, VarKey};
 use crate::machine::mock_wam::CompositeOpDir;
 use crate::machine::{BREAK_FROM_DISPATCH_LOOP_LOC, LIB_QUERY_SUCCESS};
-use crate::parser::ast::{Var, VarPtr};
+use crate::parser::ast::{Term, Var, VarPtr};
 use crate::parser::parser::{Parser, Tokens};
-use crate::read::write_term_to_heap;
+use crate::read::{write_term_to_heap, TermWriteResult};
 use indexmap::IndexMap;
 
 use super::{
@@ -37,10 +38,54 @@ pub fn consult_module_string(&mut self, module_name: &str, program: String) {
         self.run_module_predicate(atom!("loader"), (atom!("consult_stream"), 2));
     }
 
+    /// Lists the name and arity of every predicate callable from the
+    /// user module, builtins included, sorted and without duplicates.
+    /// Builtins that are compiled to instructions, like `is/2`, are
+    /// listed along with those that have code of their own. Internal
+    /// predicates, whose names begin with `$`, are left out.
+    pub fn exported_predicates(&self) -> Vec<(String, usize)> {
+        let mut predicates: Vec<_> = self
+            .indices
+            .code_dir
+            .iter()
+            .filter(|(_, code_index)| {
+                !matches!(
+                    code_index.get().tag(),
+                    IndexPtrTag::Undefined | IndexPtrTag::DynamicUndefined
+                )
+            })
+            .map(|(key, _)| *key)
+            .chain(ClauseType::inbuilt_keys())
+            .map(|(name, arity)| (name.as_str().to_string(), arity))
+            .filter(|(name, _)| !name.starts_with('$'))
+            .collect();
+
+        predicates.sort();
+        predicates.dedup();
+        predicates
+    }
+
+    /// Makes every failed unification record the first pair of
+    /// subterms that did not unify, as returned by
+    /// `last_unify_failure`. Recording is off by default.
+    pub fn set_unify_failure_recording(&mut self, record: bool) {
+        self.machine_st.record_unify_failures = record;
+        self.machine_st.last_unify_failure = None;
+    }
+
+    /// The mismatched pair of subterms of the most recent failed
+    /// unification, if failures are being recorded.
+    pub fn last_unify_failure(&self) -> Option<&(Term, Term)> {
+        self.machine_st.last_unify_failure.as_ref()
+    }
+
     fn allocate_stub_choice_point(&mut self) {
         // NOTE: create a choice point to terminate the dispatch_loop
         // if an exception is thrown.
 
+        let tr = self.machine_st.tr;
+        let h = self.machine_st.heap.len();
+
         let stub_b = self.machine_st.stack.allocate_or_frame(0);
         let or_frame = self.machine_st.stack.index_or_frame_mut(stub_b);
 
@@ -51,17 +96,28 @@ fn allocate_stub_choice_point(&mut self) {
         or_frame.prelude.bp = BREAK_FROM_DISPATCH_LOOP_LOC;
         or_frame.prelude.boip = 0;
         or_frame.prelude.biip = 0;
-        or_frame.prelude.tr = 0;
-        or_frame.prelude.h = 0;
+        // NOTE: the trail and heap marks must be those current at
+        // the time of the query. winding back to 0 would undo
+        // bindings made before the query began.
+        or_frame.prelude.tr = tr;
+        or_frame.prelude.h = h;
         or_frame.prelude.b0 = 0;
         or_frame.prelude.attr_var_queue_len = 0;
 
         self.machine_st.b = stub_b;
-        self.machine_st.hb = self.machine_st.heap.len();
+        self.machine_st.hb = h;
         self.machine_st.block = stub_b;
     }
 
-    pub fn run_query(&mut self, query: String) -> QueryResult {
+    /// Runs `query`, calling `on_solution` each time it succeeds and
+    /// backtracking for another solution until `on_solution` returns
+    /// `false` or the choice points run out. Returns whether the search
+    /// ended by failing back to the stub choice point, or the error
+    /// thrown by the query.
+    fn run_query_with<F>(&mut self, query: String, mut on_solution: F) -> Result<bool, String>
+    where
+        F: FnMut(&mut Self, &TermWriteResult, &IndexMap<HeapCellValue, VarPtr>) -> bool,
+    {
         // println!("Query: {}", query);
         // Parse the query so we can analyze and then call the term
         let mut parser = Parser::new(
@@ -107,8 +163,8 @@ pub fn run_query(&mut self, query: String) -> QueryResult {
         self.machine_st.execute_at_index(1, call_index_p);
 
         let stub_b = self.machine_st.b;
+        let mut exhausted = false;
 
-        let mut matches: Vec<QueryResolutionLine> = Vec::new();
         // Call the term
         loop {
             self.dispatch_loop();
@@ -148,66 +204,18 @@ pub fn run_query(&mut self, query: String) -> QueryResult {
                 return Err(error_string);
             }
 
-            /*
-            if self.machine_st.fail {
-                // NOTE: only print results on success
-                self.machine_st.fail = false;
-                println!("fail!");
-                matches.push(QueryResolutionLine::False);
-                break;
-            };
-            */
-
-            if self.machine_st.p == LIB_QUERY_SUCCESS {
-                if term_write_result.var_dict.is_empty() {
-                    matches.push(QueryResolutionLine::True);
-                    break;
-                }
-            } else if self.machine_st.p == BREAK_FROM_DISPATCH_LOOP_LOC {
+            if self.machine_st.p == BREAK_FROM_DISPATCH_LOOP_LOC {
                 // NOTE: only print results on success
                 // self.machine_st.fail = false;
                 // println!("b == stub_b");
-                matches.push(QueryResolutionLine::False);
+                exhausted = true;
                 break;
             }
 
-            let mut bindings: BTreeMap<String, Value> = BTreeMap::new();
-
-            for (var_key, term_to_be_printed) in &term_write_result.var_dict {
-                if var_key.to_string().starts_with('_') {
-                    continue;
-                }
-                let mut printer = HCPrinter::new(
-                    &mut self.machine_st.heap,
-                    Arc::clone(&self.machine_st.atom_tbl),
-                    &mut self.machine_st.stack,
-                    &self.indices.op_dir,
-                    PrinterOutputter::new(),
-                    *term_to_be_printed,
-                );
-
-                printer.ignore_ops = false;
-                printer.numbervars = true;
-                printer.quoted = true;
-                printer.max_depth = 1000; // NOTE: set this to 0 for unbounded depth
-                printer.double_quotes = true;
-                printer.var_names = var_names.clone();
-
-                let outputter = printer.print();
-
-                let output: String = outputter.result();
-                // println!("Result: {} = {}", var_key.to_string(), output);
-
-                if var_key.to_string() != output {
-                    bindings.insert(
-                        var_key.to_string(),
-                        Value::try_from(output).expect("Couldn't convert Houtput to Value"),
-                    );
-                }
+            if !on_solution(self, &term_write_result, &var_names) {
+                break;
             }
 
-            matches.push(QueryResolutionLine::Match(bindings));
-
             // NOTE: there are outstanding choicepoints, backtrack
             // through them for further solutions. if
             // self.machine_st.b == stub_b we've backtracked to the stub
@@ -221,13 +229,120 @@ pub fn run_query(&mut self, query: String) -> QueryResult {
             }
         }
 
-        // NOTE: deallocate stub choice point
-        if self.machine_st.b == stub_b {
+        // NOTE: deallocate the stub choice point, along with any
+        // choice points left by solutions that were not backtracked
+        // into.
+        if self.machine_st.b >= stub_b {
+            self.machine_st.b = stub_b;
             self.trust_me();
         }
 
+        Ok(exhausted)
+    }
+
+    fn solution_bindings(
+        &mut self,
+        term_write_result: &TermWriteResult,
+        var_names: &IndexMap<HeapCellValue, VarPtr>,
+    ) -> BTreeMap<String, Value> {
+        let mut bindings: BTreeMap<String, Value> = BTreeMap::new();
+
+        for (var_key, term_to_be_printed) in &term_write_result.var_dict {
+            if var_key.to_string().starts_with('_') {
+                continue;
+            }
+            let mut printer = HCPrinter::new(
+                &mut self.machine_st.heap,
+                Arc::clone(&self.machine_st.atom_tbl),
+                &mut self.machine_st.stack,
+                &self.indices.op_dir,
+                PrinterOutputter::new(),
+                *term_to_be_printed,
+            );
+
+            printer.ignore_ops = false;
+            printer.numbervars = true;
+            printer.quoted = true;
+            printer.max_depth = 1000; // NOTE: set this to 0 for unbounded depth
+            printer.double_quotes = true;
+            printer.var_names = var_names.clone();
+
+            let outputter = printer.print();
+
+            let output: String = outputter.result();
+            // println!("Result: {} = {}", var_key.to_string(), output);
+
+            if var_key.to_string() != output {
+                bindings.insert(
+                    var_key.to_string(),
+                    Value::try_from(output).expect("Couldn't convert Houtput to Value"),
+                );
+            }
+        }
+
+        bindings
+    }
+
+    pub fn run_query(&mut self, query: String) -> QueryResult {
+        let mut matches: Vec<QueryResolutionLine> = Vec::new();
+
+        let exhausted = self.run_query_with(query, |machine, term_write_result, var_names| {
+            if term_write_result.var_dict.is_empty() {
+                matches.push(QueryResolutionLine::True);
+                return false;
+            }
+
+            let bindings = machine.solution_bindings(term_write_result, var_names);
+            matches.push(QueryResolutionLine::Match(bindings));
+
+            true
+        })?;
+
+        if exhausted {
+            matches.push(QueryResolutionLine::False);
+        }
+
         Ok(QueryResolution::from(matches))
     }
+
+    /// Calls `f` with the bindings of each solution of `query` as it
+    /// is found, stopping the search as soon as `f` returns `false`.
+    /// Unlike `run_query`, every solution of a query without variables
+    /// is passed to `f`, with empty bindings.
+    pub fn for_each_solution<F>(&mut self, query: String, mut f: F) -> Result<(), String>
+    where
+        F: FnMut(&BTreeMap<String, Value>) -> bool,
+    {
+        self.run_query_with(query, |machine, term_write_result, var_names| {
+            let bindings = machine.solution_bindings(term_write_result, var_names);
+            f(&bindings)
+        })?;
+
+        Ok(())
+    }
+
+    /// Counts the solutions of `query` without printing their
+    /// bindings, stopping once `limit` solutions are found if a limit
+    /// is given. Unlike `run_query`, every solution of a query without
+    /// variables is counted.
+    pub fn count_solutions(
+        &mut self,
+        query: String,
+        limit: Option<usize>,
+    ) -> Result<usize, String> {
+        let mut count = 0;
+
+        if limit == Some(0) {
+            return Ok(count);
+        }
+
+        self.run_query_with(query, |_, _, _| {
+            count += 1;
+            limit != Some(count)
+        })?;
+
+        Ok(count)
+    }
 }
 
 #[cfg(test)]
@@ -235,7 +350,152 @@ mod tests {
     use ordered_float::OrderedFloat;
 
     use super::*;
-    use crate::machine::{QueryMatch, QueryResolution, Value};
+    use crate::machine::{Literal, QueryMatch, QueryResolution, Value};
+
+    #[test]
+    #[cfg_attr(miri, ignore = "blocked on streams.rs UB")]
+    fn for_each_solution() {
+        let mut machine = Machine::new_lib();
+
+        machine.load_module_string(
+            "facts",
+            String::from(
+                r#"
+            triple("a", "p1", "b").
+            triple("a", "p2", "b").
+            triple("a", "p3", "b").
+        "#,
+            ),
+        );
+
+        let mut seen = vec![];
+
+        let result = machine.for_each_solution(String::from(r#"triple("a",P,"b")."#), |bindings| {
+            seen.push(bindings.clone());
+            seen.len() < 2
+        });
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            seen,
+            vec![
+                btreemap! { "P".to_string() => Value::from("p1") },
+                btreemap! { "P".to_string() => Value::from("p2") },
+            ]
+        );
+
+        let mut count = 0;
+
+        let result = machine.for_each_solution(String::from(r#"triple("a",_,"b")."#), |bindings| {
+            assert!(bindings.is_empty());
+            count += 1;
+            true
+        });
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(count, 3);
+
+        assert_eq!(
+            machine.for_each_solution(String::from(r#"triple("a",P,Q,R)."#), |_| true),
+            Err(String::from(
+                "error existence_error procedure / triple 4 / triple 4"
+            ))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "blocked on streams.rs UB")]
+    fn last_unify_failure() {
+        let mut machine = Machine::new_lib();
+
+        assert_eq!(
+            machine.run_query(String::from("f(a) = f(b).")),
+            Ok(QueryResolution::False)
+        );
+        assert!(machine.last_unify_failure().is_none());
+
+        machine.set_unify_failure_recording(true);
+
+        assert_eq!(
+            machine.run_query(String::from("f(a) = f(b).")),
+            Ok(QueryResolution::False)
+        );
+        assert!(matches!(
+            machine.last_unify_failure(),
+            Some((
+                Term::Literal(_, Literal::Atom(atom!("a"))),
+                Term::Literal(_, Literal::Atom(atom!("b"))),
+            ))
+        ));
+
+        assert_eq!(
+            machine.run_query(String::from("g(X, c) = g(1, d).")),
+            Ok(QueryResolution::False)
+        );
+        assert!(matches!(
+            machine.last_unify_failure(),
+            Some((
+                Term::Literal(_, Literal::Atom(atom!("c"))),
+                Term::Literal(_, Literal::Atom(atom!("d"))),
+            ))
+        ));
+
+        machine.set_unify_failure_recording(false);
+
+        assert_eq!(
+            machine.run_query(String::from("f(a) = f(b).")),
+            Ok(QueryResolution::False)
+        );
+        assert!(machine.last_unify_failure().is_none());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "blocked on streams.rs UB")]
+    fn count_solutions() {
+        let mut machine = Machine::new_lib();
+
+        machine.load_module_string(
+            "facts",
+            String::from(
+                r#"
+            triple("a", "p1", "b").
+            triple("a", "p2", "b").
+            triple("a", "p3", "c").
+        "#,
+            ),
+        );
+
+        assert_eq!(
+            machine.count_solutions(String::from(r#"triple("a",P,O)."#), None),
+            Ok(3)
+        );
+        assert_eq!(
+            machine.count_solutions(String::from(r#"triple("a",P,O)."#), Some(2)),
+            Ok(2)
+        );
+        assert_eq!(
+            machine.count_solutions(String::from(r#"triple("a",P,O)."#), Some(5)),
+            Ok(3)
+        );
+        assert_eq!(
+            machine.count_solutions(String::from(r#"triple("a",_,"b")."#), None),
+            Ok(2)
+        );
+        assert_eq!(
+            machine.count_solutions(String::from(r#"triple("x",_,_)."#), None),
+            Ok(0)
+        );
+
+        // stopping at the limit must not leave bindings behind.
+        assert_eq!(
+            machine.run_query(String::from(r#"triple("a",P,"c")."#)),
+            Ok(QueryResolution::Matches(vec![QueryMatch::from(
+                btreemap! {
+                    "P" => Value::from("p3"),
+                }
+            )]))
+        );
+    }
 
     #[test]
     #[cfg_attr(miri, ignore = "blocked on streams.rs UB")]
@@ -452,6 +712,33 @@ fn consult() {
         );
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore = "blocked on streams.rs UB")]
+    fn exported_predicates() {
+        let mut machine = Machine::new_lib();
+
+        machine.consult_module_string(
+            "facts",
+            String::from(
+                r#"
+            :- use_module(library(lists)).
+            triple("a", "p1", "b").
+        "#,
+            ),
+        );
+
+        let predicates = machine.exported_predicates();
+
+        assert!(predicates.contains(&("member".to_string(), 2)));
+        assert!(predicates.contains(&("is".to_string(), 2)));
+        assert!(predicates.contains(&("var".to_string(), 1)));
+        assert!(predicates.contains(&("functor".to_string(), 3)));
+        assert!(predicates.contains(&("write".to_string(), 1)));
+        assert!(predicates.contains(&("triple".to_string(), 3)));
+        assert!(!predicates.contains(&("triple".to_string(), 2)));
+        assert!(predicates.iter().all(|(name, _)| !name.starts_with('$')));
+    }
+
     #[test]
     #[cfg_attr(miri, ignore = "blocked on streams.rs UB")]
     fn integration_test() {
@@ -610,6 +897,142 @@ fn non_existent_predicate_should_not_cause_panic_when_other_predicates_are_defin
         );
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore = "blocked on streams.rs UB")]
+    fn mode_is_reset_on_backtracking() {
+        let mut machine = Machine::new_lib();
+
+        machine.load_module_string(
+            "facts",
+            String::from(
+                r#"
+                write_then_read(f(X), g(a), X).
+                write_then_read(h(Y), g(b), Y).
+
+                read_then_write(g(a), f(x)).
+                read_then_write(g(b), f(y)).
+        "#,
+            ),
+        );
+
+        // the first clause builds f(X) in write mode before failing
+        // in read mode on g(a). the second clause must enter write
+        // mode afresh to build h(Y).
+        let query = String::from(r#"write_then_read(A, g(b), c)."#);
+        let output = machine.run_query(query);
+        assert_eq!(
+            output,
+            Ok(QueryResolution::Matches(vec![QueryMatch::from(
+                btreemap! {
+                    "A" => Value::from("h(c)"),
+                }
+            )]))
+        );
+
+        // the first clause fails midway through g/1 in read mode.
+        // the second clause must then build f(y) in write mode.
+        let query = String::from(r#"read_then_write(g(b), F)."#);
+        let output = machine.run_query(query);
+        assert_eq!(
+            output,
+            Ok(QueryResolution::Matches(vec![QueryMatch::from(
+                btreemap! {
+                    "F" => Value::from("f(y)"),
+                }
+            )]))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "blocked on streams.rs UB")]
+    fn structure_pointer_is_reset_on_backtracking() {
+        let mut machine = Machine::new_lib();
+
+        machine.load_module_string(
+            "facts",
+            String::from(
+                r#"
+                body_fails(f(X, _), X) :- X == z.
+                body_fails(f(_, Y), Y).
+
+                head_fails(g(a, X), X).
+                head_fails(g(Y, b), Y).
+        "#,
+            ),
+        );
+
+        // the first clause reads both arguments of f/2 before its
+        // body fails. the second must read from the first argument
+        // cell again, not from where the first clause left off.
+        let query = String::from(r#"body_fails(f(a, b), R)."#);
+        let output = machine.run_query(query);
+        assert_eq!(
+            output,
+            Ok(QueryResolution::Matches(vec![QueryMatch::from(
+                btreemap! {
+                    "R" => Value::from("b"),
+                }
+            )]))
+        );
+
+        // the first clause fails on the first argument of g/2.
+        let query = String::from(r#"head_fails(g(c, b), R)."#);
+        let output = machine.run_query(query);
+        assert_eq!(
+            output,
+            Ok(QueryResolution::Matches(vec![QueryMatch::from(
+                btreemap! {
+                    "R" => Value::from("c"),
+                }
+            )]))
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "blocked on streams.rs UB")]
+    fn no_binding_leakage_between_queries() {
+        let mut machine = Machine::new_lib();
+
+        machine.load_module_string(
+            "facts",
+            String::from(
+                r#"
+                :- dynamic(letter/1).
+
+                letter(a).
+                letter(b).
+                letter(c).
+        "#,
+            ),
+        );
+
+        let all_letters = |letters: &[&str]| {
+            Ok(QueryResolution::Matches(
+                letters
+                    .iter()
+                    .map(|letter| QueryMatch::from(btreemap! { "X" => Value::from(*letter) }))
+                    .collect(),
+            ))
+        };
+
+        let query = String::from(r#"letter(X)."#);
+        assert_eq!(machine.run_query(query), all_letters(&["a", "b", "c"]));
+
+        assert_eq!(
+            machine.run_query(String::from(r#"assertz(letter(d))."#)),
+            Ok(QueryResolution::True)
+        );
+
+        let query = String::from(r#"letter(X)."#);
+        assert_eq!(
+            machine.run_query(query),
+            all_letters(&["a", "b", "c", "d"])
+        );
+
+        let query = String::from(r#"X = d, letter(X)."#);
+        assert_eq!(machine.run_query(query), all_letters(&["d"]));
+    }
+
     #[test]
     fn issue_2341() {
         let mut machine = Machine::new_lib();
@@ -630,4 +1053,25 @@ fn issue_2341() {
 
         assert_eq!(output, Ok(QueryResolution::False));
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "blocked on streams.rs UB")]
+    fn at_end_of_byte_stream_after_peek() {
+        let mut machine = Machine::new_lib();
+        machine.set_user_input(String::from("ab"));
+
+        assert_eq!(
+            machine.run_query(String::from(
+                r#"peek_char(a), \+ at_end_of_stream."#
+            )),
+            Ok(QueryResolution::True)
+        );
+
+        assert_eq!(
+            machine.run_query(String::from(
+                r#"get_char(a), get_char(b), at_end_of_stream."#
+            )),
+            Ok(QueryResolution::True)
+        );
+    }
 }
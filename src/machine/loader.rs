@@ -2402,6 +2402,54 @@ pub(crate) fn dynamic_property(&mut self) {
         }
     }
 
+    // the module defining a predicate that Module imports, found as
+    // the module exporting it under the same code index.
+    pub(crate) fn imported_from_property(&mut self) {
+        let module_name = cell_as_atom!(self
+            .machine_st
+            .store(self.machine_st.deref(self.machine_st.registers[1])));
+
+        let key = self
+            .machine_st
+            .read_predicate_key(self.machine_st[temp_v!(2)], self.machine_st[temp_v!(3)]);
+
+        let code_index = self
+            .indices
+            .get_predicate_code_index(key.0, key.1, module_name);
+
+        let export = ModuleExport::PredicateKey(key);
+
+        // imports copy the code index of the exporting module, except
+        // for dynamic predicates without clauses, whose code index is
+        // shared.
+        let exporting_module = code_index.and_then(|code_index| {
+            let is_import_of = |export_index: &CodeIndex| {
+                *export_index == code_index
+                    || (code_index.local().is_some() && export_index.local() == code_index.local())
+            };
+
+            self.indices
+                .modules
+                .iter()
+                .find(|(name, module)| {
+                    **name != module_name
+                        && module.code_dir.get(&key).is_some_and(is_import_of)
+                        && module.module_decl.exports.contains(&export)
+                })
+                .map(|(name, _)| *name)
+        });
+
+        match exporting_module {
+            Some(name) => {
+                let target = self.machine_st.registers[4];
+                unify!(self.machine_st, atom_as_cell!(name), target);
+            }
+            None => {
+                self.machine_st.fail = true;
+            }
+        }
+    }
+
     pub(crate) fn multifile_property(&mut self) {
         let module_name = cell_as_atom!(self
             .machine_st
@@ -479,6 +479,16 @@ pub(super) fn instantiation_error(&mut self) -> MachineError {
         }
     }
 
+    pub(super) fn uninstantiation_error(&mut self, culprit: HeapCellValue) -> MachineError {
+        let stub = functor!(atom!("uninstantiation_error"), [cell(culprit)]);
+
+        MachineError {
+            stub,
+            location: None,
+            from: ErrorProvenance::Received,
+        }
+    }
+
     pub(super) fn session_error(&mut self, err: SessionError) -> MachineError {
         match err {
             SessionError::CannotOverwriteBuiltIn(key) => self.permission_error(
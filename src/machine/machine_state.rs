@@ -61,6 +61,10 @@ pub struct MachineState {
     pub atom_tbl: Arc<AtomTable>,
     pub arena: Arena,
     pub(super) pdl: Vec<HeapCellValue>,
+    // like mode, s and s_offset aren't saved in choice points. they
+    // are re-established by the get_* instruction heading every
+    // structure the next clause reads, never carried over from the
+    // failed one.
     pub(super) s: HeapPtr,
     pub(super) s_offset: usize,
     pub(super) p: usize,
@@ -74,6 +78,11 @@ pub struct MachineState {
     pub(super) attr_var_init: AttrVarInitializer,
     pub(super) fail: bool,
     pub heap: Heap,
+    // mode isn't saved in choice points. the compiler never emits a
+    // unify_* instruction that isn't preceded by a get_structure,
+    // get_list or get_partial_string in the same clause, and each of
+    // those sets mode, so a stale mode can't survive backtracking
+    // into the next clause.
     pub(super) mode: MachineMode,
     pub(crate) stack: Stack,
     pub(super) registers: Registers,
@@ -94,6 +103,10 @@ pub struct MachineState {
     pub(crate) global_clock: usize,
     pub(crate) dynamic_mode: FirstOrNext,
     pub(crate) unify_fn: fn(&mut MachineState),
+    // when set, a failed unification records the first pair of
+    // subterms it could not unify in last_unify_failure.
+    pub(crate) record_unify_failures: bool,
+    pub(crate) last_unify_failure: Option<(Term, Term)>,
     pub(crate) bind_fn: fn(&mut MachineState, Ref, HeapCellValue),
     pub(crate) run_cleaners_fn: fn(&mut Machine) -> bool,
 }
@@ -58,6 +58,8 @@ pub(crate) fn new() -> Self {
             global_clock: 0,
             dynamic_mode: FirstOrNext::First,
             unify_fn: MachineState::unify,
+            record_unify_failures: false,
+            last_unify_failure: None,
             bind_fn: MachineState::bind,
             run_cleaners_fn: |_| false,
         }
@@ -256,6 +258,19 @@ pub fn unify(&mut self) {
         unifier.unify_internal();
     }
 
+    // only reached once unification has failed, so successful
+    // unifications don't pay for the check. d1 was popped from the
+    // PDL before d2, so it belongs to the right-hand side of the
+    // unification.
+    pub(super) fn record_unify_failure(&mut self, d1: HeapCellValue, d2: HeapCellValue) {
+        if self.record_unify_failures {
+            let left = self.read_term_from_heap(d2);
+            let right = self.read_term_from_heap(d1);
+
+            self.last_unify_failure = Some((left, right));
+        }
+    }
+
     pub fn unify_structure(&mut self, s1: usize, value: HeapCellValue) {
         let mut unifier = DefaultUnifier::from(self);
         unifier.unify_structure(s1, value);
@@ -718,5 +718,25 @@ fn is_cyclic_term_tests() {
         wam.heap.push(empty_list_as_cell!());
 
         assert!(!wam.is_cyclic_term(pstr_loc_as_cell!(0)));
+
+        wam.heap.clear();
+
+        // [g(1), p, f(x)] as compiled in a clause body, with [p, f(x)]
+        // stored as the partial string "p" whose tail is [f(x)].
+        wam.heap.extend(functor!(atom!("g"), [fixnum(1)]));
+        wam.heap.extend(functor!(atom!("f"), [atom(atom!("x"))]));
+        wam.heap.push(str_loc_as_cell!(2));
+        wam.heap.push(empty_list_as_cell!());
+        wam.heap.push(pstr_as_cell!(atom!("p")));
+        wam.heap.push(list_loc_as_cell!(4));
+        wam.heap.push(str_loc_as_cell!(0));
+        wam.heap.push(pstr_loc_as_cell!(6));
+
+        let heap = wam.heap.clone();
+
+        assert!(!wam.is_cyclic_term(list_loc_as_cell!(8)));
+
+        all_cells_unmarked(&wam.heap);
+        assert_eq!(wam.heap, heap);
     }
 }
@@ -587,6 +587,11 @@ pub(crate) fn run_verify_attr_interrupt(&mut self, arity: usize) {
     }
 
     fn next_clause_applicable(&mut self, mut offset: usize) -> bool {
+        // the heap address of the next argument of a structure or list
+        // matched by a shallow get instruction, which lets the
+        // constants among its unify instructions be checked too.
+        let mut subterm: Option<usize> = None;
+
         loop {
             match &self.code[offset] {
                 Instruction::IndexingCode(indexing_lines) => {
@@ -631,6 +636,7 @@ fn next_clause_applicable(&mut self, mut offset: usize) -> bool {
                 }
                 &Instruction::GetConstant(Level::Shallow, lit, RegType::Temp(t)) => {
                     let cell = self.deref_register(t);
+                    subterm = None;
 
                     if cell.is_var() {
                         offset += 1;
@@ -673,9 +679,14 @@ fn next_clause_applicable(&mut self, mut offset: usize) -> bool {
                 }
                 &Instruction::GetList(Level::Shallow, RegType::Temp(t)) => {
                     let cell = self.deref_register(t);
+                    subterm = None;
 
                     read_heap_cell!(cell,
-                        (HeapCellValueTag::Lis | HeapCellValueTag::PStrLoc | HeapCellValueTag::CStr) => {
+                        (HeapCellValueTag::Lis, l) => {
+                            subterm = Some(l);
+                            offset += 1;
+                        }
+                        (HeapCellValueTag::PStrLoc | HeapCellValueTag::CStr) => {
                             offset += 1;
                         }
                         (HeapCellValueTag::Str, s) => {
@@ -697,10 +708,12 @@ fn next_clause_applicable(&mut self, mut offset: usize) -> bool {
                 }
                 &Instruction::GetStructure(Level::Shallow, name, arity, RegType::Temp(t)) => {
                     let cell = self.deref_register(t);
+                    subterm = None;
 
                     read_heap_cell!(cell,
                         (HeapCellValueTag::Str, s) => {
                             if (name, arity) == cell_as_atom_cell!(self.machine_st.heap[s]).get_name_and_arity() {
+                                subterm = Some(s + 1);
                                 offset += 1;
                             } else {
                                 return false;
@@ -721,6 +734,7 @@ fn next_clause_applicable(&mut self, mut offset: usize) -> bool {
                     has_tail,
                 ) => {
                     let cell = self.deref_register(t);
+                    subterm = None;
 
                     read_heap_cell!(cell,
                         (HeapCellValueTag::CStr, cstr) => {
@@ -750,17 +764,41 @@ fn next_clause_applicable(&mut self, mut offset: usize) -> bool {
                         }
                     );
                 }
+                &Instruction::UnifyConstant(lit) => {
+                    if let Some(h) = subterm {
+                        let cell = self.machine_st.deref(self.machine_st.heap[h]);
+
+                        if !cell.is_var() && lit.get_tag() != HeapCellValueTag::CStr {
+                            self.machine_st.write_literal_to_var(cell, lit);
+
+                            if self.machine_st.fail {
+                                self.machine_st.fail = false;
+                                return false;
+                            }
+                        }
+
+                        subterm = Some(h + 1);
+                    }
+
+                    offset += 1;
+                }
+                &Instruction::UnifyVoid(n) => {
+                    subterm = subterm.map(|h| h + n);
+                    offset += 1;
+                }
+                &Instruction::UnifyVariable(..)
+                | &Instruction::UnifyValue(..)
+                | &Instruction::UnifyLocalValue(..) => {
+                    subterm = subterm.map(|h| h + 1);
+                    offset += 1;
+                }
                 Instruction::GetConstant(..)
                 | Instruction::GetList(..)
                 | Instruction::GetStructure(..)
                 | Instruction::GetPartialString(..)
-                | &Instruction::UnifyVoid(..)
-                | &Instruction::UnifyConstant(..)
                 | &Instruction::GetVariable(..)
-                | &Instruction::GetValue(..)
-                | &Instruction::UnifyVariable(..)
-                | &Instruction::UnifyValue(..)
-                | &Instruction::UnifyLocalValue(..) => {
+                | &Instruction::GetValue(..) => {
+                    subterm = None;
                     offset += 1;
                 }
                 _ => {
@@ -1283,6 +1321,12 @@ fn run_cleaners(&mut self) -> bool {
     }
 
     pub(super) fn unwind_trail(&mut self, a1: usize, a2: usize) {
+        // a1 is the trail mark of the choice point being resumed and
+        // a2 the current trail top. unwinding past either end would
+        // respectively undo older bindings or leave stale ones.
+        debug_assert!(a1 <= a2);
+        debug_assert_eq!(a2, self.machine_st.trail.len());
+
         // the sequence is reversed to respect the chronology of trail
         // additions now that deleted attributes can be undeleted by
         // backtracking.
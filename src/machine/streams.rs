@@ -1071,8 +1071,19 @@ pub(crate) fn position_relative_to_end(&mut self) -> AtEndOfStream {
                     ..
                 } = &mut **stream_layout;
 
-                let cursor_len = stream.get_ref().0.get_ref().len() as u64;
-                cursor_position(past_end_of_stream, &stream.get_ref().0, cursor_len)
+                // bytes already taken into the char reader's buffer
+                // have not been read yet.
+                let cursor = &stream.get_ref().0;
+                let position = cursor.position() - stream.rem_buf_len() as u64;
+
+                match position.cmp(&(cursor.get_ref().len() as u64)) {
+                    Ordering::Equal => AtEndOfStream::At,
+                    Ordering::Less => AtEndOfStream::Not,
+                    Ordering::Greater => {
+                        *past_end_of_stream = true;
+                        AtEndOfStream::Past
+                    }
+                }
             }
             Stream::StaticString(stream_layout) => {
                 let StreamLayout {
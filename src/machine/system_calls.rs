@@ -29,6 +29,7 @@
 use crate::machine::{get_structure_index, Machine, VERIFY_ATTR_INTERRUPT_LOC};
 use crate::parser::char_reader::*;
 use crate::parser::dashu::Integer;
+use crate::parser::lexer::{Lexer, Token};
 use crate::read::*;
 use crate::types::*;
 use rand::rngs::StdRng;
@@ -43,7 +44,7 @@
 
 use std::cell::Cell;
 use std::cmp::Ordering;
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, VecDeque};
 use std::convert::TryFrom;
 use std::env;
 #[cfg(feature = "ffi")]
@@ -514,6 +515,15 @@ enum MatchSite {
     Match(usize),          // a match
 }
 
+// the code of the '$clause'/2 entries of a dynamic predicate.
+#[derive(Debug)]
+enum ClauseClauseCode<'a> {
+    // the code of the only entry.
+    Entry(usize),
+    // the entries, as offsets from a code index.
+    Indexed(usize, &'a VecDeque<IndexedChoiceInstruction>),
+}
+
 #[derive(Debug)]
 struct AttrListMatch {
     match_site: MatchSite,
@@ -1163,9 +1173,47 @@ pub(crate) fn get_clause_p(&self, module_name: Atom) -> (usize, usize) {
             .get_predicate_skeleton(&compilation_target, &key)
             .unwrap();
 
-        let module_name = match compilation_target {
-            CompilationTarget::User => atom!("builtins"),
-            CompilationTarget::Module(target) => target,
+        match self.clause_clause_code(&key, module_name) {
+            ClauseClauseCode::Entry(p) => {
+                (skeleton.core.clause_clause_locs.back().cloned().unwrap(), p)
+            }
+            ClauseClauseCode::Indexed(bp, indexed_choice) => {
+                let p = if self.machine_st.b > self.machine_st.e {
+                    // this means the last
+                    // self.machine_st.iip value has yet
+                    // to be overwritten by the Trust
+                    // instruction. In this case, return
+                    // it.
+                    self.machine_st.iip as usize
+                } else {
+                    // otherwise, read the '$clause'
+                    // choicepoint from the top of the
+                    // stack. this is very volatile in
+                    // that it depends on '$clause'
+                    // immediately preceding
+                    // '$get_clause_p', which cannot be
+                    // the last clause of the retract
+                    // helper to delay deallocation of its
+                    // environment frame.
+                    let clause_b = self.machine_st.stack.top();
+                    self.machine_st.stack.index_or_frame(clause_b).prelude.biip as usize
+                };
+
+                (
+                    skeleton.core.clause_clause_locs[p],
+                    bp + indexed_choice[p].offset(),
+                )
+            }
+        }
+    }
+
+    // the code of the '$clause'/2 entries of the dynamic predicate key
+    // of module_name, reached through the indexing code of '$clause'/2.
+    fn clause_clause_code(&self, key: &PredicateKey, module_name: Atom) -> ClauseClauseCode<'_> {
+        let module_name = if module_name == atom!("user") {
+            atom!("builtins")
+        } else {
+            module_name
         };
 
         let mut bp = self
@@ -1177,12 +1225,7 @@ pub(crate) fn get_clause_p(&self, module_name: Atom) -> (usize, usize) {
         macro_rules! extract_ptr {
             ($ptr: expr) => {
                 match $ptr {
-                    IndexingCodePtr::External(p) => {
-                        return (
-                            skeleton.core.clause_clause_locs.back().cloned().unwrap(),
-                            bp + p,
-                        )
-                    }
+                    IndexingCodePtr::External(p) => return ClauseClauseCode::Entry(bp + p),
                     IndexingCodePtr::Internal(boip) => boip,
                     _ => unreachable!(),
                 }
@@ -1215,7 +1258,7 @@ macro_rules! extract_ptr {
 
                     let boip = match &indexing_code[boip] {
                         IndexingLine::Indexing(IndexingInstruction::SwitchOnStructure(ref hm)) => {
-                            boip + extract_ptr!(hm.get(&key).cloned().unwrap())
+                            boip + extract_ptr!(hm.get(key).cloned().unwrap())
                         }
                         IndexingLine::Indexing(IndexingInstruction::SwitchOnConstant(ref hm)) => {
                             boip + extract_ptr!(hm.get(&Literal::Atom(key.0)).cloned().unwrap())
@@ -1225,31 +1268,7 @@ macro_rules! extract_ptr {
 
                     match &indexing_code[boip] {
                         IndexingLine::IndexedChoice(indexed_choice) => {
-                            let p = if self.machine_st.b > self.machine_st.e {
-                                // this means the last
-                                // self.machine_st.iip value has yet
-                                // to be overwritten by the Trust
-                                // instruction. In this case, return
-                                // it.
-                                self.machine_st.iip as usize
-                            } else {
-                                // otherwise, read the '$clause'
-                                // choicepoint from the top of the
-                                // stack. this is very volatile in
-                                // that it depends on '$clause'
-                                // immediately preceding
-                                // '$get_clause_p', which cannot be
-                                // the last clause of the retract
-                                // helper to delay deallocation of its
-                                // environment frame.
-                                let clause_b = self.machine_st.stack.top();
-                                self.machine_st.stack.index_or_frame(clause_b).prelude.biip as usize
-                            };
-
-                            return (
-                                skeleton.core.clause_clause_locs[p],
-                                bp + indexed_choice[p].offset(),
-                            );
+                            return ClauseClauseCode::Indexed(bp, indexed_choice);
                         }
                         _ => unreachable!(),
                     }
@@ -1258,12 +1277,116 @@ macro_rules! extract_ptr {
                     bp -= offset;
                 }
                 _ => {
-                    return (
-                        skeleton.core.clause_clause_locs.back().cloned().unwrap(),
-                        bp,
-                    );
+                    return ClauseClauseCode::Entry(bp);
+                }
+            }
+        }
+    }
+
+    // the location of the '$clause'/2 entry of the clause at a
+    // position of a dynamic predicate, counting from 0, or of its last
+    // clause. it stays the same for as long as the clause exists.
+    #[inline(always)]
+    pub(crate) fn get_clause_loc(&mut self) {
+        use crate::machine::loader::CompilationTarget;
+
+        let key_cell = self.machine_st.registers[1];
+        let key = self.machine_st.name_and_arity_from_heap(key_cell).unwrap();
+
+        let pos = self.deref_register(2);
+        let module_name = cell_as_atom!(self.deref_register(3));
+
+        let compilation_target = if module_name == atom!("user") {
+            CompilationTarget::User
+        } else {
+            CompilationTarget::Module(module_name)
+        };
+
+        let loc = self
+            .indices
+            .get_predicate_skeleton(&compilation_target, &key)
+            .and_then(|skeleton| {
+                let locs = &skeleton.core.clause_clause_locs;
+
+                match Number::try_from(pos) {
+                    Ok(Number::Fixnum(n)) => {
+                        usize::try_from(n.get_num()).ok().and_then(|n| locs.get(n))
+                    }
+                    _ => locs.back(),
+                }
+            })
+            .cloned();
+
+        match loc {
+            Some(loc) => {
+                let target = self.deref_register(4);
+                self.machine_st
+                    .unify_fixnum(Fixnum::build_with(loc as i64), target);
+            }
+            None => {
+                self.machine_st.fail = true;
+            }
+        }
+    }
+
+    // the code of the '$clause'/2 entry at location l, as returned by
+    // get_clause_loc, after unifying the current position of its
+    // clause in the predicate with register 4. fails if the clause
+    // was retracted.
+    #[inline(always)]
+    pub(crate) fn clause_at_loc(&mut self) -> Option<usize> {
+        use crate::machine::loader::CompilationTarget;
+
+        let key_cell = self.machine_st.registers[1];
+        let key = self.machine_st.name_and_arity_from_heap(key_cell).unwrap();
+
+        let l = match Number::try_from(self.deref_register(3)) {
+            Ok(Number::Fixnum(l)) => usize::try_from(l.get_num()).ok(),
+            _ => None,
+        };
+
+        let l = match l {
+            Some(l) => l,
+            None => {
+                self.machine_st.fail = true;
+                return None;
+            }
+        };
+
+        let module_name = cell_as_atom!(self.deref_register(5));
+
+        let compilation_target = if module_name == atom!("user") {
+            CompilationTarget::User
+        } else {
+            CompilationTarget::Module(module_name)
+        };
+
+        let n = self
+            .indices
+            .get_predicate_skeleton_mut(&compilation_target, &key)
+            .and_then(|skeleton| skeleton.target_pos_of_clause_clause_loc(l));
+
+        match n {
+            Some(n) => {
+                let target = self.deref_register(4);
+                self.machine_st
+                    .unify_fixnum(Fixnum::build_with(n as i64), target);
+
+                if self.machine_st.fail {
+                    return None;
+                }
+
+                match self.clause_clause_code(&key, module_name) {
+                    ClauseClauseCode::Entry(p) => Some(p),
+                    ClauseClauseCode::Indexed(bp, indexed_choice) => {
+                        Some(bp + indexed_choice[n].offset())
+                    }
                 }
             }
+            None => {
+                self.machine_st.fail = true;
+                None
+            }
         }
     }
 
@@ -3877,10 +4000,13 @@ pub(crate) fn lookup_db_ref(&mut self) {
             return;
         }
 
+        // a code index is created for every predicate a clause refers
+        // to, whether or not it is ever defined.
         self.machine_st.fail = self
             .indices
             .get_predicate_code_index(name, arity, module_name)
-            .is_none();
+            .map(|code_index| code_index.get().is_undefined())
+            .unwrap_or(true);
     }
 
     #[inline(always)]
@@ -3955,8 +4081,8 @@ pub(crate) fn get_db_refs(&mut self) {
             }
         };
 
-        for (name, arity) in code_dir.keys() {
-            if self.indices.builtin_property((*name, *arity)) {
+        for ((name, arity), code_index) in code_dir.iter() {
+            if self.indices.builtin_property((*name, *arity)) || code_index.get().is_undefined() {
                 continue;
             }
 
@@ -5031,6 +5157,29 @@ pub(crate) fn open(&mut self) -> CallResult {
         Ok(())
     }
 
+    // a text stream kept in memory: characters written to it can be
+    // read back from it, in order.
+    #[inline(always)]
+    pub(crate) fn open_memory_stream(&mut self) -> CallResult {
+        let culprit = self.deref_register(1);
+
+        let stream_var = match culprit.as_var() {
+            Some(stream_var) => stream_var,
+            None => {
+                let err = self.machine_st.uninstantiation_error(culprit);
+                let stub = functor_stub(atom!("$open_memory_stream"), 1);
+
+                return Err(self.machine_st.error_form(err, stub));
+            }
+        };
+
+        let stream = Stream::from_owned_string(String::new(), &mut self.machine_st.arena);
+        self.indices.streams.insert(stream);
+
+        self.machine_st.bind(stream_var, stream_as_cell!(stream));
+        Ok(())
+    }
+
     #[inline(always)]
     pub(crate) fn op_declaration(&mut self) -> CallResult {
         let priority = self.deref_register(1);
@@ -6028,6 +6177,14 @@ pub(crate) fn get_current_scc_block(&mut self) {
         self.machine_st.unify_fixnum(block, addr);
     }
 
+    #[inline(always)]
+    pub(crate) fn get_global_clock(&mut self) {
+        let addr = self.machine_st.registers[1];
+        let clock = Fixnum::build_with(self.machine_st.global_clock as i64);
+
+        self.machine_st.unify_fixnum(clock, addr);
+    }
+
     #[inline(always)]
     pub(crate) fn get_b_value(&mut self) {
         let n = Fixnum::as_cutpoint(i64::try_from(self.machine_st.b).unwrap());
@@ -6288,6 +6445,100 @@ pub(crate) fn read_term_from_chars(&mut self) -> CallResult {
         }
     }
 
+    #[inline(always)]
+    pub(crate) fn read_tokens_from_chars(&mut self) -> CallResult {
+        let string = match self
+            .machine_st
+            .value_to_str_like(self.machine_st.registers[1])
+        {
+            Some(AtomOrString::Atom(atom!("[]"))) => "".to_owned(),
+            Some(atom_or_string) => atom_or_string.into(),
+            None => unreachable!(),
+        };
+
+        let mut tokens = vec![];
+        let mut lexer = Lexer::new(
+            CharReader::new(ByteStream::from_string(string)),
+            &mut self.machine_st,
+        );
+
+        // NOTE: layout is skipped here rather than in next_token so
+        // that running out of input between tokens can be told apart
+        // from running out inside one, which is a syntax error.
+        let result = loop {
+            let layout_inserted = match lexer.scan_for_layout() {
+                Ok(layout_inserted) => layout_inserted,
+                Err(e) if e.is_unexpected_eof() => break Ok(()),
+                Err(e) => break Err(e),
+            };
+
+            match lexer.lookahead_char() {
+                Ok('\u{0}') => break Ok(()),
+                Err(e) if e.is_unexpected_eof() => break Ok(()),
+                _ => {}
+            }
+
+            match lexer.next_token() {
+                Ok(Token::OpenCT) if layout_inserted => tokens.push(Token::Open),
+                Ok(token) => tokens.push(token),
+                Err(e) => break Err(e),
+            }
+        };
+
+        if let Err(e) = result {
+            let stub = functor_stub(atom!("read_tokens_from_chars"), 2);
+            let e = self.machine_st.session_error(SessionError::from(e));
+
+            return Err(self.machine_st.error_form(e, stub));
+        }
+
+        let mut token_cells = Vec::with_capacity(tokens.len());
+
+        for token in tokens {
+            let token_cell = match token {
+                Token::Literal(literal @ (Literal::Atom(_) | Literal::Char(_))) => {
+                    functor!(atom!("name"), [literal(literal)])
+                }
+                Token::Literal(literal @ Literal::String(_)) => {
+                    functor!(atom!("string"), [literal(literal)])
+                }
+                Token::Literal(literal) => {
+                    functor!(atom!("number"), [literal(literal)])
+                }
+                Token::Var(name) => {
+                    let name = AtomTable::build_with(&self.machine_st.atom_tbl, &name);
+                    functor!(atom!("var"), [atom(name)])
+                }
+                Token::Open => functor!(atom!("punct"), [atom(atom!("("))]),
+                Token::OpenCT => functor!(atom!("open_ct")),
+                Token::Close => functor!(atom!("punct"), [atom(atom!(")"))]),
+                Token::OpenList => functor!(atom!("punct"), [atom(atom!("["))]),
+                Token::CloseList => functor!(atom!("punct"), [atom(atom!("]"))]),
+                Token::OpenCurly => functor!(atom!("punct"), [atom(atom!("{"))]),
+                Token::CloseCurly => functor!(atom!("punct"), [atom(atom!("}"))]),
+                Token::HeadTailSeparator => functor!(atom!("punct"), [atom(atom!("|"))]),
+                Token::Comma => functor!(atom!("punct"), [atom(atom!(","))]),
+                Token::End => functor!(atom!("end")),
+            };
+
+            if token_cell.len() == 1 {
+                token_cells.push(token_cell[0]);
+            } else {
+                let h = self.machine_st.heap.len();
+                self.machine_st.heap.extend(token_cell);
+                token_cells.push(str_loc_as_cell!(h));
+            }
+        }
+
+        let tokens_list = heap_loc_as_cell!(iter_to_heap_list(
+            &mut self.machine_st.heap,
+            token_cells.into_iter()
+        ));
+
+        unify!(self.machine_st, self.machine_st.registers[2], tokens_list);
+        Ok(())
+    }
+
     #[inline(always)]
     pub(crate) fn reset_block(&mut self) {
         let addr = self.deref_register(1);
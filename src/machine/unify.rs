@@ -626,6 +626,7 @@ fn unify_internal(&mut self) {
                             }
                             _ => {
                                 self.fail = true;
+                                self.record_unify_failure(d1, d2);
                                 break;
                             }
                         );
@@ -656,11 +657,16 @@ fn unify_internal(&mut self) {
                              HeapCellValueTag::PStrLoc) => {
                             }
                             (HeapCellValueTag::CStr) => {
-                                self.fail = d1 != d2;
+                                if d1 != d2 {
+                                    self.fail = true;
+                                    self.record_unify_failure(d1, d2);
+                                }
+
                                 continue;
                             }
                             _ => {
                                 self.fail = true;
+                                self.record_unify_failure(d1, d2);
                                 return;
                             }
                         );
@@ -686,6 +692,10 @@ fn unify_internal(&mut self) {
                         unreachable!();
                     }
                 );
+
+                if self.fail {
+                    self.record_unify_failure(d1, d2);
+                }
             }
         }
     }
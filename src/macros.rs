@@ -536,6 +536,21 @@ macro_rules! functor_term {
     (number($arena:expr, $e:expr), $arity:expr, $aux_lens:expr, $addendum:ident) => (
         HeapCellValue::from(($e, $arena))
     );
+    (arith_term($h:expr, $arena:expr, $e:expr), $arity:expr, $aux_lens:expr, $addendum:ident) => ({
+        match $e {
+            ArithmeticTerm::Number(n) => HeapCellValue::from((n, &mut *$arena)),
+            at => {
+                let stub = at.into_functor(&mut *$arena);
+
+                let len: usize = $aux_lens.iter().sum();
+                let h = len + $arity + 1 + $addendum.len() + $h;
+
+                $addendum.extend(stub.into_iter());
+
+                str_loc_as_cell!(h)
+            }
+        }
+    });
     (atom($e:expr), $arity:expr, $aux_lens:expr, $addendum:ident) => (
         atom_as_cell!($e)
     );
@@ -260,6 +260,13 @@ test("acyclic_term#2131", (
     \+ acyclic_term(C)
 )).
 
+test("acyclic_term leaves lists with partial string tails intact", (
+    L = [g(1), p, f(x)],
+    acyclic_term(L),
+    L = [_|T],
+    T == [p, f(x)]
+)).
+
 main :-
     findall(test(Name, Goal), test(Name, Goal), Tests),
     run_tests(Tests, Failed),
@@ -0,0 +1,93 @@
+:- module(arithmetic_tests, []).
+
+:- use_module(test_framework).
+
+factorial(0, 1) :- !.
+factorial(N, F) :-
+    N1 is N - 1,
+    factorial(N1, F1),
+    F is N * F1.
+
+% X is the rational number N/D, and not merely equal to it.
+rational_is(X, N, D) :-
+    R is N rdiv D,
+    X == R.
+
+test("powers of two are computed exactly", (
+    X is 2 ^ 100,
+    integer(X),
+    X == 1267650600228229401496703205376
+)).
+
+test("factorial of 30 is computed exactly", (
+    factorial(30, F),
+    F == 265252859812191058636308480000000
+)).
+
+test("addition promotes on overflow rather than wrapping", (
+    X is 9223372036854775807 + 1,
+    X == 9223372036854775808,
+    Y is 36028797018963967 + 1,
+    Y == 36028797018963968
+)).
+
+test("multiplication promotes on overflow rather than wrapping", (
+    X is 4294967296 * 4294967296,
+    X == 18446744073709551616,
+    Y is -4294967296 * 4294967296,
+    Y == -18446744073709551616
+)).
+
+test("big integer literals are read exactly", (
+    X = -123456789012345678901234567890,
+    integer(X),
+    Y is X + 123456789012345678901234567890,
+    Y == 0
+)).
+
+test("results small enough for a fixnum are demoted", (
+    X is 2 ^ 100 - (2 ^ 100 - 1),
+    X == 1
+)).
+
+test("sums of rationals are exact and reduced", (
+    X is 1 rdiv 3 + 1 rdiv 6,
+    rational_is(X, 1, 2)
+)).
+
+test("rationals with a denominator of one are integers", (
+    X is 1 rdiv 3 * 3,
+    X == 1
+)).
+
+test("rational/1 converts floats exactly", (
+    X is rational(0.25),
+    rational_is(X, 1, 4),
+    Y is rational(0.1),
+    rational_is(Y, 3602879701896397, 36028797018963968),
+    Z is rational(2.0),
+    Z == 2
+)).
+
+test("rationalize/1 finds the simplest rational of a float", (
+    X is rationalize(0.1),
+    rational_is(X, 1, 10),
+    Y is rationalize(-0.75),
+    rational_is(Y, -3, 4)
+)).
+
+test("rational/1 and rationalize/1 leave exact numbers unchanged", (
+    X is rational(1 rdiv 3),
+    rational_is(X, 1, 3),
+    Y is rationalize(7),
+    Y == 7
+)).
+
+test("rational/1 and rationalize/1 evaluate by meta-call", (
+    E1 = rational(0.5),
+    X is E1,
+    rational_is(X, 1, 2),
+    E2 = rationalize(0.2),
+    Y is E2,
+    rational_is(Y, 1, 5)
+)).
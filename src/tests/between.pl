@@ -0,0 +1,40 @@
+:- module(between_tests, []).
+
+:- use_module(library(between)).
+:- use_module(library(iso_ext)).
+
+:- use_module(test_framework).
+
+test("between/4 generates every step up to the upper bound", (
+    findall(X, between(0, 10, 2, X), Xs),
+    Xs == [0, 2, 4, 6, 8, 10]
+)).
+
+test("between/4 stops at the last step below the upper bound", (
+    findall(X, between(1, 10, 4, X), Xs),
+    Xs == [1, 5, 9],
+    findall(X, between(-3, 3, 3, X), Ys),
+    Ys == [-3, 0, 3]
+)).
+
+test("between/4 leaves no choice point after the last step", (
+    call_cleanup(between(0, 10, 5, X), Det = true),
+    X == 10,
+    Det == true
+)).
+
+test("between/4 checks whether an integer is on a step", (
+    between(1, 10, 3, 7),
+    \+ between(1, 10, 3, 8),
+    \+ between(1, 10, 3, 13),
+    \+ between(5, 1, 1, _)
+)).
+
+test("between/4 requires a positive step", (
+    catch(( between(0, 10, 0, _), false ),
+          error(domain_error(positive_integer, 0), _),
+          true),
+    catch(( between(0, 10, _, _), false ),
+          error(instantiation_error, _),
+          true)
+)).
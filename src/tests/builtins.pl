@@ -1,8 +1,19 @@
 :- module(tests_on_builtins, []).
 
+:- use_module(library(charsio)).
 :- use_module(library(lists)).
 :- use_module(library(iso_ext)).
 
+:- dynamic(ref_fact/1).
+
+assertz_ref_facts(N) :-
+    (  N =:= 0 ->
+       true
+    ;  assertz(ref_fact(N), _),
+       N1 is N - 1,
+       assertz_ref_facts(N1)
+    ).
+
 test_queries_on_builtins :-
     \+ atom(_),
     atom(a),
@@ -57,6 +68,13 @@ test_queries_on_builtins :-
     compound(f(_)),
     compound([1,2,3]),
     \+ compound([]),
+    [] == '[]',
+    '[]' == [],
+    atom_length([], 2),
+    write_term_to_chars('[]', [quoted(true)], Cs1),
+    Cs1 == "[]",
+    write_term_to_chars([a|'[]'], [quoted(true)], Cs2),
+    Cs2 == "[a]",
     \+ compound(3.14159269),
     \+ compound(3),
     compound("sdfsa"),
@@ -115,8 +133,92 @@ test_queries_on_builtins :-
     1.1 @< 1,
     1.0 @=< 1,
     \+ 1 @=< 1.0,
+    % a head needing more temporary registers than there are must be
+    % rejected at compile time rather than crash when called.
+    \+ \+ ( length(Ls1, 600),
+            length(Ls2, 600),
+            F1 =.. [f|Ls1],
+            F2 =.. [g|Ls2],
+            F3 =.. [h|Ls1],
+            F4 =.. [k|Ls2],
+            catch(( assertz(too_many_registers(F1, F2, F3, F4)), false ),
+                  error(representation_error(max_arity), _),
+                  true) ),
+    % builtins are static and can't be redefined by assertion.
+    catch(( assertz((_ is _ :- true)), false ),
+          error(permission_error(modify, static_procedure, (is)/2), _),
+          true),
+    catch(( asserta(atom_length(_, 0)), false ),
+          error(permission_error(modify, static_procedure, atom_length/2), _),
+          true),
+    catch(( retract((_ is _ :- _)), false ),
+          error(permission_error(modify, static_procedure, (is)/2), _),
+          true),
+    X0 is 1 + 1,
+    X0 == 2,
     keysort([1-1,1-1],[1-1,1-1]),
     \+ \+ findall(Sorted, keysort([2-99,1-a,3-f(_),1-z,1-a,2-44],Sorted), [[1-a,1-z,1-a,2-99,2-44,3-f(_)]]),
-    \+ \+ findall(X, keysort([X-1,1-1],[2-1,1-1]), [2]).
+    \+ \+ findall(X, keysort([X-1,1-1],[2-1,1-1]), [2]),
+    apply(append([a]), [[b], X1]),
+    X1 == [a,b],
+    apply(atom_length, [abc, 3]),
+    \+ apply(atom_length, [abc, 2]),
+    catch(( apply(append([a]), _), false ),
+          error(instantiation_error, _),
+          true),
+    % erasing one of two identical clauses by reference leaves the other.
+    assertz(ref_fact(1), Ref1),
+    assertz(ref_fact(1), Ref2),
+    Ref1 \== Ref2,
+    erase(Ref2),
+    findall(X2, ref_fact(X2), [1]),
+    clause(ref_fact(1), true, Ref1),
+    \+ clause(_, _, Ref2),
+    \+ erase(Ref2),
+    erase(Ref1),
+    \+ ref_fact(_),
+    % asserta/2 refers to the new first clause.
+    assertz(ref_fact(1), Ref3),
+    asserta(ref_fact(0), Ref4),
+    findall(X4-R4, clause(ref_fact(X4), true, R4), [0-Ref4, 1-Ref3]),
+    retractall(ref_fact(_)),
+    % references are minted without visiting the other clauses, so
+    % asserting N clauses takes a number of inferences linear in N.
+    call_with_inference_limit(assertz_ref_facts(1000), 100000, R5),
+    R5 \== inference_limit_exceeded,
+    retractall(ref_fact(_)),
+    % clause/2 finds the clauses of user-qualified heads, as clause/3
+    % relies on.
+    assertz(user:ref_fact(2)),
+    clause(user:ref_fact(X3), true),
+    X3 == 2,
+    retract(user:ref_fact(2)),
+    % forged references are type checked, and refer to no clause
+    % unless they name a clause of a dynamic predicate.
+    catch(( erase('$clause_ref'(user, ref_fact/1, x)), false ),
+          error(type_error(db_reference, _), _),
+          true),
+    catch(( clause(_, _, '$clause_ref'(1, ref_fact/1, 0)), false ),
+          error(type_error(db_reference, _), _),
+          true),
+    \+ erase('$clause_ref'(user, ref_fact/1, 1000000)),
+    \+ erase('$clause_ref'(lists, append/3, 0)),
+    \+ clause(_, _, '$clause_ref'(no_such_module, p/1, 0)),
+    predicate_property(tests_on_builtins:append(_, _, _), imported_from(M0)),
+    M0 == lists,
+    \+ predicate_property(tests_on_builtins:assertz_ref_facts(_), imported_from(_)),
+    \+ predicate_property(lists:append(_, _, _), imported_from(_)),
+    current_op(P0, xfx, =),
+    P0 == 700,
+    findall(T0, current_op(_, T0, -), Ts0),
+    sort(Ts0, [fy, yfx]),
+    \+ current_op(_, _, ===>),
+    op(650, xfx, ===>),
+    findall(P1-T1, current_op(P1, T1, ===>), [650-xfx]),
+    op(0, xfx, ===>),
+    \+ current_op(_, _, ===>),
+    catch(( current_op(1201, _, _), false ),
+          error(domain_error(operator_priority, 1201), _),
+          true).
 
 :- initialization(test_queries_on_builtins).
@@ -0,0 +1,114 @@
+:- module(charsio_tests, []).
+
+:- use_module(library(charsio)).
+:- use_module(library(iso_ext)).
+:- use_module(library(lists)).
+
+:- use_module(test_framework).
+
+% Goal succeeds without leaving a choice point.
+deterministic(Goal) :-
+    call_cleanup(Goal, Det = true),
+    (   Det == true
+    ->  true
+    ;   !,
+        false
+    ).
+
+test("sub_string/5 enumerates all substrings", (
+    findall(B-L-A-Sub, sub_string("abc", B, L, A, Sub), Subs),
+    Subs == [0-0-3-[], 0-1-2-"a", 0-2-1-"ab", 0-3-0-"abc",
+             1-0-2-[], 1-1-1-"b", 1-2-0-"bc",
+             2-0-1-[], 2-1-0-"c",
+             3-0-0-[]]
+)).
+
+test("sub_string/5 finds the positions of a substring", (
+    findall(B-A, sub_string("abab", B, _, A, "ab"), Positions),
+    Positions == [0-2, 2-0]
+)).
+
+test("sub_string/5 extracts by position and length", (
+    sub_string("abcde", 1, 3, A, Sub),
+    A == 1,
+    Sub == "bcd"
+)).
+
+test("sub_string/5 extracts suffixes and prefixes", (
+    findall(Sub, sub_string("abcde", _, 2, 0, Sub), ["de"]),
+    findall(Sub, sub_string("abcde", 0, _, 3, Sub), ["ab"])
+)).
+
+test("sub_string/5 fails for absent substrings", (
+    \+ sub_string("abcde", _, _, _, "ca")
+)).
+
+test("sub_string/5 checks its arguments", (
+    catch(( sub_string(_, _, _, _, _), false ),
+          error(instantiation_error, _),
+          true),
+    catch(( sub_string("abc", -1, _, _, _), false ),
+          error(domain_error(not_less_than_zero, -1), sub_string/5),
+          true),
+    catch(( sub_string("abc", _, _, _, a), false ),
+          error(type_error(list, a), _),
+          true)
+)).
+
+test("sub_string/5 is deterministic once the position is determined", (
+    deterministic(sub_string("abcde", 1, 3, _, "bcd")),
+    deterministic(sub_string("abcde", 1, _, 1, _)),
+    deterministic(sub_string("abcde", _, 3, 1, _)),
+    deterministic(sub_string("abcde", 1, _, _, "bcd")),
+    deterministic(sub_string("abcde", _, _, 1, "bcd"))
+)).
+
+test("sub_string/5 leaves no choice point after the last position", (
+    findall(B, sub_string("abab", B, _, _, "ab"), [0, 2]),
+    call_cleanup(sub_string("abab", B, _, _, "ab"), Det = true),
+    B == 2,
+    Det == true
+)).
+
+test("sub_string/5 takes a substring in time linear in its position", (
+    length(String, 100000),
+    maplist(=(a), String),
+    call_with_inference_limit(sub_string(String, 99990, 5, A, Sub),
+                              1000000,
+                              Result),
+    Result == !,
+    A == 5,
+    Sub == "aaaaa"
+)).
+
+test("read_term_from_chars/3 reports singleton variables by name", (
+    read_term_from_chars("f(X, Y, X).", T, [singletons(S)]),
+    T = f(_, Y, _),
+    S == ['Y'=Y]
+)).
+
+test("read_term_from_chars/3 leaves anonymous variables out of singletons", (
+    read_term_from_chars("f(_, _Z, W, W).", T, [singletons(S)]),
+    T = f(_, Z, _, _),
+    S == ['_Z'=Z],
+    read_term_from_chars("g(X, X).", _, [singletons([])])
+)).
+
+test("read_tokens_from_codes/2 tokenizes a code list", (
+    atom_codes('foo(X, 12) .', Codes),
+    read_tokens_from_codes(Codes, [First | Tokens]),
+    First == name(foo),
+    Tokens == [open_ct, var('X'), punct(','), number(12), punct(')'), end]
+)).
+
+test("read_tokens_from_chars/2 tells functional notation from a bracket", (
+    read_tokens_from_chars("- (a)", Ts),
+    Ts == [name(-), punct('('), name(a), punct(')')]
+)).
+
+test("read_tokens_from_chars/2 rejects a token cut short by the end of input", (
+    read_tokens_from_chars("  % comment\n", []),
+    catch(( read_tokens_from_chars("a 'b", _), false ),
+          error(syntax_error(_), _),
+          true)
+)).
@@ -0,0 +1,80 @@
+:- module(compilation_tests, []).
+
+:- use_module(library(diag)).
+:- use_module(library(lists)).
+
+:- use_module(test_framework).
+
+:- dynamic(deep_head/1).
+:- dynamic(deep_body/0).
+:- dynamic(folded/1).
+:- dynamic(unfolded/1).
+:- dynamic(less/2).
+:- dynamic(unbound_less/2).
+
+nested_term(0, a) :- !.
+nested_term(N, f(g(T), b)) :-
+    N1 is N - 1,
+    nested_term(N1, T).
+
+instructions(PI, Is) :-
+    wam_instructions(compilation_tests:PI, Is).
+
+% the distinct temporary registers x(R) used by the code of PI.
+temp_registers(PI, Rs) :-
+    instructions(PI, Is),
+    findall(R, ( member(I, Is), I =.. [_|As], member(x(R), As) ), Rs0),
+    sort(Rs0, Rs).
+
+arithmetic_instruction(I) :-
+    functor(I, Name, _),
+    member(Name, [add, sub, mul]).
+
+test("temporary registers are reused in deeply nested heads", (
+    nested_term(100, T),
+    assertz(deep_head(T)),
+    temp_registers(deep_head/1, Rs),
+    length(Rs, N),
+    N =< 2
+)).
+
+test("temporary registers are reused in deeply nested goals", (
+    nested_term(100, T),
+    assertz((deep_body :- call(=, T, _))),
+    temp_registers(deep_body/0, Rs),
+    length(Rs, N),
+    N =< 5
+)).
+
+test("ground is/2 expressions are folded at compile time", (
+    assertz((folded(X) :- X is 2 + 3 * 4 - 1)),
+    instructions(folded/1, Is),
+    \+ ( member(I, Is), arithmetic_instruction(I) ),
+    memberchk(execute(is, 2, x(1), 13), Is),
+    folded(X),
+    X == 13
+)).
+
+test("is/2 expressions that overflow a fixnum are not folded", (
+    assertz((unfolded(X) :- X is 36028797018963967 * 2)),
+    instructions(unfolded/1, Is),
+    once(( member(I, Is), arithmetic_instruction(I) )),
+    unfolded(X),
+    X =:= 72057594037927934
+)).
+
+test("arithmetic comparisons are compiled inline", (
+    assertz((less(X, Y) :- X < Y)),
+    instructions(less/2, Is),
+    memberchk(execute(<, 2, x(1), x(2)), Is),
+    \+ memberchk(execute(<, 2), Is),
+    less(1, 2),
+    \+ less(2, 1)
+)).
+
+test("type tests are compiled inline", (
+    assertz((unbound_less(X, Y) :- var(X), X == Y, Y < 1)),
+    instructions(unbound_less/2, Is),
+    memberchk(call(var, 1, x(1)), Is),
+    memberchk(execute(<, 2, x(1), 1), Is)
+)).
@@ -0,0 +1,92 @@
+:- module(copy_term_tests, []).
+
+:- use_module(library(charsio)).
+:- use_module(library(lists)).
+
+:- use_module(test_framework).
+
+:- dynamic(wide_clause/2).
+:- dynamic(bound_head/2).
+
+% [X1, X1, X2, X2, ..., Xn, Xn] for fresh variables X1, ..., Xn.
+paired_vars(0, []) :- !.
+paired_vars(N, [X, X | Xs]) :-
+    N1 is N - 1,
+    paired_vars(N1, Xs).
+
+% each variable occurs exactly twice, side by side, and nowhere else.
+paired(Xs) :-
+    term_variables(Xs, Vs),
+    length(Xs, L),
+    length(Vs, N),
+    L =:= 2 * N,
+    pairs_shared(Xs).
+
+pairs_shared([]).
+pairs_shared([X, Y | Xs]) :-
+    X == Y,
+    pairs_shared(Xs).
+
+test("copy_term/2 preserves variable sharing in a large term", (
+    paired_vars(50000, Xs),
+    copy_term(Xs, Ys),
+    paired(Ys),
+    Xs = [X | _],
+    Ys = [Y | _],
+    X \== Y
+)).
+
+test("a large asserted clause is instantiated with its sharing intact", (
+    paired_vars(1000, Xs),
+    Xs = [First | _],
+    retractall(wide_clause(_, _)),
+    assertz((wide_clause(First, Ys) :- Ys = Xs)),
+    wide_clause(a, Zs),
+    Zs = [a, a | Rest],
+    paired(Rest),
+    clause(copy_term_tests:wide_clause(H, Ws0), (Ws1 = Ws)),
+    Ws0 == Ws1,
+    Ws = [H0, H1 | _],
+    H0 == H,
+    H1 == H,
+    paired(Ws)
+)).
+
+test("an asserted clause keeps the bindings its head had when asserted", (
+    retractall(bound_head(_, _)),
+    A = B,
+    B = f(X, "ab", [Y|X]),
+    assertz((bound_head(A, Y) :- X = Y)),
+    X = 1,
+    Y = 2,
+    clause(copy_term_tests:bound_head(H, Z), Body),
+    H = f(X0, Cs, [Y0|X1]),
+    Cs == "ab",
+    X0 == X1,
+    Y0 == Z,
+    var(X0),
+    var(Z),
+    X0 \== Z,
+    Body == (X0 = Z)
+)).
+
+test("binding a variable after assertz/1 does not change the stored clause", (
+    retractall(bound_head(_, _)),
+    T = s(U),
+    U = t(V),
+    assertz(bound_head(T, V)),
+    V = v,
+    bound_head(s(t(W)), W1),
+    var(W),
+    W == W1,
+    \+ bound_head(s(t(v)), w)
+)).
+
+test("a variable reached through a bound list cell is written under one name", (
+    paired_vars(1, Xs),
+    Xs = [_ | _],
+    write_term_to_chars(Xs, [], Cs),
+    Cs = ['[' | Cs1],
+    append(Name, [',' | Cs2], Cs1),
+    append(Name, [']'], Cs2)
+)).
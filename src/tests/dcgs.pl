@@ -0,0 +1,92 @@
+:- module(dcgs_tests, []).
+
+:- use_module(library(dcgs)).
+:- use_module(library(iso_ext)).
+:- use_module(library(lists)).
+
+:- use_module(test_framework).
+
+as(0) --> !.
+as(N) -->
+    [a],
+    { N1 is N - 1 },
+    as(N1).
+
+abs(0) --> !.
+abs(N) -->
+    as(1),
+    "b",
+    { N1 is N - 1 },
+    abs(N1).
+
+% grammar bodies are threaded through difference lists, so describing
+% a sequence of N elements must take a number of inferences linear in
+% N. a quadratic translation would exceed these limits by far.
+linear(Goal, N) :-
+    Limit is 50 * N,
+    call_with_inference_limit(Goal, Limit, Result),
+    Result \== inference_limit_exceeded.
+
+test("recursive nonterminals generate long sequences in linear time", (
+    N = 20000,
+    linear(phrase(as(N), Ls), N),
+    length(Ls, N)
+)).
+
+test("concatenated bodies generate long sequences in linear time", (
+    N = 10000,
+    linear(phrase(abs(N), Ls), N),
+    length(Ls, L),
+    L =:= 2 * N
+)).
+
+test("seq//1 describes long sequences in linear time", (
+    N = 20000,
+    length(Es, N),
+    linear(phrase(seq(Es), Ls), N),
+    length(Ls, N)
+)).
+
+test("concatenation leaves the rest of the list open", (
+    phrase((as(2), "b"), Ls, Rest),
+    Rest = "c",
+    Ls == "aabc"
+)).
+
+test("append_dl/3 concatenates difference lists", (
+    append_dl([a,b|T1]-T1, [c|T2]-T2, DL),
+    DL == [a,b,c|T2]-T2,
+    T2 = [d],
+    DL = Ls-_,
+    Ls == "abcd"
+)).
+
+test("list_to_dlist/2 and dlist_to_list/2 are inverses", (
+    list_to_dlist("abc", DL),
+    DL = _-Tail,
+    var(Tail),
+    dlist_to_list(DL, Ls),
+    Ls == "abc"
+)).
+
+test("difference lists from phrase/3 concatenate with append_dl/3", (
+    phrase(as(2), Ls1, Ls10),
+    list_to_dlist("b", DL2),
+    append_dl(Ls1-Ls10, DL2, DL),
+    dlist_to_list(DL, Ls),
+    Ls == "aab"
+)).
+
+test("append_dl/3 concatenates many difference lists in linear time", (
+    N = 20000,
+    length(Es, N),
+    maplist(element_dlist, Es, DLs),
+    linear(foldl(append_dl_to, DLs, Ls-Ls, DL), N),
+    dlist_to_list(DL, Ls1),
+    length(Ls1, N)
+)).
+
+element_dlist(E, [E|Es]-Es).
+
+append_dl_to(DL, DL0, DL1) :-
+    append_dl(DL0, DL, DL1).
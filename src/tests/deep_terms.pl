@@ -0,0 +1,44 @@
+:- module(deep_terms_tests, []).
+
+:- use_module(test_framework).
+
+% f(f(...f(Leaf)...)), nested Depth times. terms this deep must be
+% traversed with explicit stacks rather than by native recursion, or
+% they overflow the Rust stack.
+deep_term(0, Leaf, Leaf) :- !.
+deep_term(Depth, Leaf, f(T)) :-
+    Depth1 is Depth - 1,
+    deep_term(Depth1, Leaf, T).
+
+test("deeply nested terms are structurally equal", (
+    deep_term(100000, a, T),
+    deep_term(100000, a, U),
+    T == U
+)).
+
+test("deeply nested terms are ordered by their innermost difference", (
+    deep_term(100000, a, T),
+    deep_term(100000, b, U),
+    compare(Order, T, U),
+    Order == (<)
+)).
+
+test("deeply nested terms unify", (
+    deep_term(100000, X, T),
+    deep_term(100000, a, U),
+    T = U,
+    X == a
+)).
+
+test("deeply nested terms are copied", (
+    deep_term(100000, _, T),
+    copy_term(T, U),
+    T \== U,
+    T = U
+)).
+
+test("deeply nested terms are reconstructed from findall/3", (
+    deep_term(100000, a, T),
+    findall(T, true, [U]),
+    T == U
+)).
@@ -0,0 +1,39 @@
+:- module(findall_tests, []).
+
+:- use_module(library(between)).
+:- use_module(library(lists)).
+
+:- use_module(test_framework).
+
+% solutions are copied into a separate lifted heap as they are found
+% and only turned into a list on the main heap once the goal is
+% exhausted, so neither large nor nested solution sets interleave with
+% the terms built by the goal.
+
+test("findall/3 collects a large solution set in order", (
+    findall(I-f(I), between(1, 50000, I), Sols),
+    length(Sols, 50000),
+    Sols = [1-f(1), 2-f(2) | _],
+    append(_, [50000-f(50000)], Sols)
+)).
+
+test("nested findall/3 keeps each solution set apart", (
+    findall(I-Js,
+            ( between(1, 3, I),
+              findall(J, between(1, I, J), Js) ),
+            Sols),
+    Sols == [1-[1], 2-[1, 2], 3-[1, 2, 3]]
+)).
+
+test("findall/4 appends to a given tail", (
+    findall(I, between(1, 3, I), Sols, [end]),
+    Sols == [1, 2, 3, end]
+)).
+
+test("findall/3 discards partial solutions when the goal throws", (
+    catch(findall(I, ( between(1, 10, I), I > 5, throw(stop) ), _),
+          stop,
+          true),
+    findall(I, between(1, 3, I), Sols),
+    Sols == [1, 2, 3]
+)).
@@ -0,0 +1,72 @@
+:- module(format_tests, []).
+
+:- use_module(library(dcgs)).
+:- use_module(library(format)).
+
+:- use_module(test_framework).
+
+test("format/3 writes to atoms", (
+    format(atom(A), "~w-~w", [a, b]),
+    A == 'a-b'
+)).
+
+test("format/3 writes to strings", (
+    format(string(S), "~a~d", [x, 12]),
+    S == "x12",
+    format(chars(Cs), "~q", ['A']),
+    Cs == "'A'"
+)).
+
+test("format/3 writes to codes", (
+    format(codes(Cs), "ab~n", []),
+    Cs == [0'a, 0'b, 0'\n]
+)).
+
+test("format/3 accepts sinks bound at runtime", (
+    Sink = atom(A),
+    format(Sink, "~s", ["abc"]),
+    A == abc
+)).
+
+test("format/3 requires a sink", (
+    catch(( format(_, "~w", [a]), false ),
+          error(instantiation_error, format/3),
+          true)
+)).
+
+test("~t and ~| right-align a two-column table", (
+    phrase(format_("~t~w~10|~t~w~8+~n~t~w~10|~t~w~8+~n",
+                   [apple, 3, kiwi, 120]),
+           Cs),
+    Cs == "     apple       3\n      kiwi     120\n"
+)).
+
+test("~t after the text left-aligns a column", (
+    phrase(format_("~w~t~6|~w~n", [ab, cd]), Cs),
+    Cs == "ab    cd\n"
+)).
+
+test("~t on both sides centers a column", (
+    phrase(format_("~t~w~t~9|", [mid]), Cs),
+    Cs == "   mid   "
+)).
+
+test("~`ct fills a column with the character c", (
+    phrase(format_("~w~`.t~8|~w", [a, b]), Cs),
+    Cs == "a.......b"
+)).
+
+test("portray_clause/1 indents each body goal on its own line", (
+    phrase(portray_clause_((foo(X) :- bar(X), baz(X))), Cs),
+    Cs == "foo(A) :-\n   bar(A),\n   baz(A).\n"
+)).
+
+test("portray_clause/1 writes facts quoted on a single line", (
+    phrase(portray_clause_(f(_, 'A', "ab")), Cs),
+    Cs == "f(A,'A',\"ab\").\n"
+)).
+
+test("portray_clause/1 lays out if-then-else over several lines", (
+    phrase(portray_clause_((p(X, Y) :- ( X > 0 -> Y = pos ; Y = neg ))), Cs),
+    Cs == "p(A,B) :-\n   (  A>0 ->\n      B=pos\n   ;  B=neg\n   ).\n"
+)).
@@ -0,0 +1,35 @@
+:- module(gensym_tests, []).
+
+:- use_module(library(gensym)).
+
+:- use_module(test_framework).
+
+test("gensym/2 numbers successive atoms", (
+    reset_gensym(node),
+    gensym(node, X),
+    gensym(node, Y),
+    gensym(node, Z),
+    [X, Y, Z] == [node1, node2, node3]
+)).
+
+test("gensym/2 keeps a counter per base", (
+    reset_gensym(a),
+    reset_gensym(b),
+    gensym(a, A1),
+    gensym(b, B1),
+    gensym(a, A2),
+    [A1, B1, A2] == [a1, b1, a2]
+)).
+
+test("reset_gensym/1 restarts the counter", (
+    gensym(node, _),
+    reset_gensym(node),
+    gensym(node, X),
+    X == node1
+)).
+
+test("gensym/2 requires a fresh variable", (
+    catch(( gensym(node, node1), false ),
+          error(uninstantiation_error(node1), _),
+          true)
+)).
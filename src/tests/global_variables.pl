@@ -0,0 +1,40 @@
+:- module(global_variables_tests, []).
+
+:- use_module(library(atts), [term_attributed_variables/2]).
+:- use_module(library(dif)).
+:- use_module(library(freeze)).
+:- use_module(library(iso_ext)).
+
+:- use_module(test_framework).
+
+test("nb_setval/2 stores a copy without attributes", (
+    freeze(X, false),
+    dif(Y, a),
+    nb_setval(global_term, f(X, Y)),
+    nb_getval(global_term, T),
+    T = f(X1, Y1),
+    term_attributed_variables(T, []),
+    X1 = b,
+    Y1 = a
+)).
+
+test("nb_setval/2 leaves the attributes of the original variables", (
+    dif(Y, a),
+    nb_setval(global_term, Y),
+    term_attributed_variables(Y, [_]),
+    \+ Y = a
+)).
+
+test("bb_put/2 keeps the attributes of the stored term", (
+    dif(Y, a),
+    bb_put(global_term, f(Y)),
+    bb_get(global_term, T),
+    term_attributed_variables(T, [_]),
+    \+ T = f(a)
+)).
+
+test("nb_getval/2 requires an atom key", (
+    catch(( nb_getval(1, _), false ),
+          error(type_error(atom, 1), nb_getval/2),
+          true)
+)).
@@ -0,0 +1,133 @@
+:- module(indexing_tests, []).
+
+:- use_module(library(between)).
+:- use_module(library(diag)).
+:- use_module(library(iso_ext)).
+:- use_module(library(lists)).
+
+:- use_module(test_framework).
+
+:- dynamic(bulk_fact/2).
+
+% assertz/1 extends the first argument index of a dynamic predicate
+% in place rather than rebuilding it, so bulk loads take linear time
+% and the index is usable as soon as the last clause is added.
+bulk_assertz(N) :-
+    retractall(bulk_fact(_, _)),
+    (   between(1, N, I),
+        V is I * 2,
+        assertz(bulk_fact(I, V)),
+        false
+    ;   true
+    ).
+
+% Goal succeeds without leaving a choice point. the cut stops later
+% solutions, which may well be deterministic, from being tried.
+deterministic(Goal) :-
+    call_cleanup(Goal, Det = true),
+    (   Det == true
+    ->  true
+    ;   !,
+        false
+    ).
+
+instructions(PI, Is) :-
+    wam_instructions(indexing_tests:PI, Is).
+
+% compound first arguments are indexed on their name and arity, lists
+% apart from other compounds.
+keyed(f(X), f(X)).
+keyed(g(X, Y), g(X, Y)).
+keyed([a|_], list).
+keyed(f(a), fa).
+
+% every first argument selects a single clause.
+unique_key(red, 1).
+unique_key(green, 2).
+unique_key(7, 3).
+unique_key([], 4).
+unique_key([_|_], 5).
+unique_key(h(_), 6).
+unique_key(blue, 7).
+
+% the remaining clauses share a principal functor with the first, and
+% differ only in the constants inside it or in a later argument.
+nested_key(f(a), [1], 1).
+nested_key(f(b), [2], 2).
+nested_key(f(_), [3|_], 3).
+
+test("bulk assertz keeps the first argument index correct", (
+    bulk_assertz(10000),
+    deterministic(bulk_fact(1, V1)),
+    V1 == 2,
+    deterministic(bulk_fact(5000, V2)),
+    V2 == 10000,
+    deterministic(bulk_fact(10000, V3)),
+    V3 == 20000,
+    \+ bulk_fact(10001, _)
+)).
+
+test("bulk assertz keeps every clause in order", (
+    bulk_assertz(10000),
+    findall(I, bulk_fact(I, _), Is),
+    length(Is, 10000),
+    Is = [1, 2 | _],
+    append(_, [10000], Is)
+)).
+
+test("the index is extended by asserta after a bulk assertz", (
+    bulk_assertz(10000),
+    asserta(bulk_fact(0, 0)),
+    deterministic(bulk_fact(0, V)),
+    V == 0,
+    findall(I, bulk_fact(I, _), [0, 1 | _])
+)).
+
+test("compound first arguments are switched on by name and arity", (
+    instructions(keyed/2, Is),
+    memberchk(switch_on_structure(Keys), Is),
+    memberchk((f/1):_, Keys),
+    memberchk((g/2):_, Keys),
+    \+ memberchk(('.'/2):_, Keys)
+)).
+
+test("only clauses with the matching principal functor are tried", (
+    deterministic(keyed(g(1, 2), G)),
+    G == g(1, 2),
+    deterministic(keyed([a, b], L)),
+    L == list,
+    findall(F, keyed(f(a), F), [f(a), fa]),
+    \+ keyed(h(a), _)
+)).
+
+test("a first argument matching a single clause leaves no choice point", (
+    deterministic(unique_key(red, A)),
+    A == 1,
+    deterministic(unique_key(7, B)),
+    B == 3,
+    deterministic(unique_key([], C)),
+    C == 4,
+    deterministic(unique_key([x], D)),
+    D == 5,
+    deterministic(unique_key(h(x), E)),
+    E == 6,
+    deterministic(unique_key(blue, F)),
+    F == 7
+)).
+
+test("an unbound first argument still tries every clause", (
+    \+ deterministic(unique_key(_, _)),
+    findall(V, unique_key(_, V), [1, 2, 3, 4, 5, 6, 7])
+)).
+
+test("clauses whose heads cannot match leave no choice point", (
+    deterministic(nested_key(f(a), [1], A)),
+    A == 1,
+    deterministic(nested_key(_, [2], B)),
+    B == 2,
+    deterministic(nested_key(f(X), [3, 4], C)),
+    var(X),
+    C == 3,
+    \+ deterministic(nested_key(f(_), _, _)),
+    findall(V, nested_key(_, _, V), [1, 2, 3])
+)).
@@ -0,0 +1,26 @@
+:- module(lists_tests, []).
+
+:- use_module(library(iso_ext)).
+:- use_module(library(lists)).
+
+:- use_module(test_framework).
+
+test("memberchk/2 succeeds deterministically on a match", (
+    call_cleanup(memberchk(b, [a, b, c]), Det = true),
+    Det == true
+)).
+
+test("memberchk/2 fails when no element matches", (
+    \+ memberchk(z, [a, b, c]),
+    \+ memberchk(_, [])
+)).
+
+test("memberchk/2 commits to the first matching element", (
+    findall(V, memberchk(a-V, [b-0, a-1, a-2]), Vs),
+    Vs == [1]
+)).
+
+test("memberchk/2 extends a partial list with the element", (
+    memberchk(c, [a, b | T]),
+    T = [c | _]
+)).
@@ -0,0 +1,59 @@
+:- module(memo_tests, []).
+
+:- use_module(library(iso_ext)).
+:- use_module(library(memo)).
+
+:- use_module(test_framework).
+
+:- dynamic(memo_fact/1).
+
+% counts its calls in a global variable rather than the database,
+% since any assertion would invalidate the cache.
+counted(X) :-
+    bb_get(memo_calls, N0),
+    N is N0 + 1,
+    bb_put(memo_calls, N),
+    memo_fact(X).
+
+reset_counted :-
+    memo_clear,
+    bb_put(memo_calls, 0),
+    retractall(memo_fact(_)).
+
+test("memo/1 runs an identical ground goal only once", (
+    reset_counted,
+    assertz(memo_fact(a)),
+    memo(counted(a)),
+    memo(counted(a)),
+    bb_get(memo_calls, 1)
+)).
+
+test("memo/1 caches failure", (
+    reset_counted,
+    \+ memo(counted(b)),
+    \+ memo(counted(b)),
+    bb_get(memo_calls, 1)
+)).
+
+test("memo/1 is invalidated by assertz", (
+    reset_counted,
+    \+ memo(counted(c)),
+    assertz(memo_fact(c)),
+    memo(counted(c)),
+    bb_get(memo_calls, 2)
+)).
+
+test("memo/1 is invalidated by retract", (
+    reset_counted,
+    assertz(memo_fact(d)),
+    memo(counted(d)),
+    retract(memo_fact(d)),
+    \+ memo(counted(d)),
+    bb_get(memo_calls, 2)
+)).
+
+test("memo/1 requires a ground goal", (
+    catch(( memo(counted(_)), false ),
+          error(instantiation_error, _),
+          true)
+)).
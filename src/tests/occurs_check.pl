@@ -0,0 +1,59 @@
+:- module(occurs_check_tests, []).
+
+:- use_module(library(iso_ext)).
+
+:- use_module(test_framework).
+
+with_occurs_check(Mode, Goal) :-
+    current_prolog_flag(occurs_check, Mode0),
+    setup_call_cleanup(set_prolog_flag(occurs_check, Mode),
+                       Goal,
+                       set_prolog_flag(occurs_check, Mode0)).
+
+% binding the second argument to the first creates a cycle.
+cyclic_head(f(X), X).
+
+test("occurs_check=error throws on cyclic unification", (
+    catch(( with_occurs_check(error, X = f(X)), false ),
+          error(representation_error(term), _),
+          true)
+)).
+
+test("occurs_check=true fails on cyclic unification", (
+    \+ with_occurs_check(true, X = f(X))
+)).
+
+test("occurs_check=false creates cyclic terms", (
+    \+ \+ ( with_occurs_check(false, X = f(X)),
+            \+ acyclic_term(X) )
+)).
+
+test("occurs_check=error throws on cyclic head unification", (
+    catch(( with_occurs_check(error, cyclic_head(Y, Y)), false ),
+          error(representation_error(term), _),
+          true)
+)).
+
+test("occurs_check=true fails on cyclic head unification", (
+    with_occurs_check(true, ( cyclic_head(Y, Y) -> false ; true ))
+)).
+
+test("unify_with_occurs_check/2 fails on cyclic unification", (
+    \+ unify_with_occurs_check(X, f(X)),
+    \+ unify_with_occurs_check(f(X, g(Y)), f(Y, X)),
+    var(X),
+    var(Y)
+)).
+
+test("unify_with_occurs_check/2 binds as (=)/2 otherwise", (
+    unify_with_occurs_check(X, a),
+    X == a,
+    unify_with_occurs_check(f(A, g(B)), f(b, g(A))),
+    A == b,
+    B == b
+)).
+
+test("unify_with_occurs_check/2 fails whatever the occurs_check flag", (
+    \+ with_occurs_check(false, unify_with_occurs_check(X, f(X))),
+    \+ with_occurs_check(error, unify_with_occurs_check(X, f(X)))
+)).
@@ -0,0 +1,83 @@
+:- module(parser_tests, []).
+
+:- use_module(library(charsio)).
+
+:- use_module(test_framework).
+
+syntax_error(Cs) :-
+    catch(( read_from_chars(Cs, _), false ),
+          error(syntax_error(_), _),
+          true).
+
+test("the bar separates the tail of a list", (
+    read_from_chars("[a|b].", T),
+    T == '.'(a, b)
+)).
+
+test("the bar separates the tail after several elements", (
+    read_from_chars("[a,b|[c]].", T),
+    T == [a,b,c]
+)).
+
+test("only one bar may occur in a list", syntax_error("[a|b|c].")).
+
+test("the bar must be followed by a tail", syntax_error("[a|].")).
+
+test("the bar must be preceded by an element", syntax_error("[|a].")).
+
+test("nothing may follow the tail", syntax_error("[a|b,c].")).
+
+test("a stray bar outside a list is a syntax error", syntax_error("a | b.")).
+
+test("a stray bar in arguments is a syntax error", syntax_error("f(a|b).")).
+
+test("a quoted bar is an ordinary atom", (
+    read_from_chars("'|'(a,b).", T),
+    functor(T, '|', 2)
+)).
+
+test("a bar operator doesn't change how list tails are read", (
+    op(1100, xfy, '|'),
+    read_from_chars("(a|b).", T1),
+    read_from_chars("[a|b].", T2),
+    read_from_chars("[(a|b)].", T3),
+    op(0, xfy, '|'),
+    T1 == '|'(a, b),
+    T2 == '.'(a, b),
+    T3 == ['|'(a, b)],
+    syntax_error("a | b.")
+)).
+
+test("0' takes the control escapes of quoted atoms", (
+    X1 is 0'\n,
+    X1 == 10,
+    X2 is 0'\t,
+    X2 == 9,
+    X3 is 0'\a,
+    X3 == 7,
+    read_from_chars("0'\\r.", X4),
+    X4 == 13
+)).
+
+test("0' takes escaped quotes and backslashes", (
+    X1 is 0'\\,
+    X1 == 92,
+    X2 is 0'\',
+    X2 == 39,
+    X3 is 0''',
+    X3 == 39,
+    X4 is 0'\",
+    X4 == 34
+)).
+
+test("0' takes octal and hexadecimal escapes", (
+    X1 is 0'\101\,
+    X1 == 65,
+    X2 is 0'\x263a\,
+    X2 == 9786
+)).
+
+test("0' rejects escapes that quoted atoms reject", (
+    syntax_error("X = 0'\\z."),
+    syntax_error("X = '\\z'.")
+)).
@@ -0,0 +1,26 @@
+% print/1 defers to portray/1, which must be defined in the user
+% module, so unlike the other tests this file is not a module.
+
+:- use_module(library(format)).
+
+portray(point(X, Y)) :-
+    format("<~w,~w>", [X, Y]).
+portray(secret) :-
+    write('***').
+
+print_tests :-
+    print(point(1, 2)), nl,
+    print([point(1, 2), p, f(secret, 'A b')]), nl,
+    print(1 - (point(a, b) - c)), nl,
+    print([a|secret]), nl,
+    print(f(point)), nl.
+
+% the in-memory stream behind print/1 is only ever opened on a fresh
+% variable.
+memory_stream_tests :-
+    catch(open_memory_stream(s),
+          error(uninstantiation_error(s), _),
+          (write(uninstantiation_error), nl)).
+
+open_memory_stream(S) :-
+    '$open_memory_stream'(S).
@@ -1,6 +1,48 @@
 :- module(test_on_setup_call_cleanup, []).
 
 :- use_module(library(iso_ext)).
+:- use_module(library(lists)).
+
+:- dynamic(cleanups/1).
+
+reset_cleanups :-
+    retractall(cleanups(_)),
+    assertz(cleanups(0)).
+
+% the number of times the cleanup handler of Goal runs, whether Goal
+% succeeds, fails or throws.
+count_cleanups(Goal, N) :-
+    reset_cleanups,
+    (   catch(setup_call_cleanup(true, Goal, count_cleanup), _, true) ->
+        true
+    ;   true
+    ),
+    cleanups(N).
+
+count_call_cleanups(Goal, N) :-
+    reset_cleanups,
+    (   catch(call_cleanup(Goal, count_cleanup), _, true) ->
+        true
+    ;   true
+    ),
+    cleanups(N).
+
+% the number of times the cleanup handler runs as all solutions of a
+% nondeterministic goal are collected.
+count_exhausted_cleanups(N) :-
+    reset_cleanups,
+    findall(X, setup_call_cleanup(true, member(X, [1,2,3]), count_cleanup), [1,2,3]),
+    cleanups(N).
+
+count_exhausted_call_cleanups(N) :-
+    reset_cleanups,
+    findall(X, call_cleanup(member(X, [1,2,3]), count_cleanup), [1,2,3]),
+    cleanups(N).
+
+count_cleanup :-
+    retract(cleanups(N0)),
+    N is N0 + 1,
+    assertz(cleanups(N)).
 
 test_queries_on_setup_call_cleanup :-
     \+ setup_call_cleanup(false, _, _),
@@ -23,6 +65,24 @@ test_queries_on_setup_call_cleanup :-
     catch((setup_call_cleanup(S=1,(G=2;G=3), write_term(S+G>B, [variable_names(['S'=S,'G'=G,'B'=B])])), B=4, !, throw(x)), x, true),
     findall(Pat, catch(setup_call_cleanup(true,throw(goal),throw(cl)), Pat, true), [goal]),
     findall(Pat, catch(( setup_call_cleanup(true,(G=1;G=2),throw(cl)), throw(cont)), Pat, true), [cont]),
-    findall([X,Y], (setup_call_cleanup(true, (X=1;X=2), writeq(a)), setup_call_cleanup(true,(Y=1;Y=2),writeq(b)), !), [[1,1]]).
+    findall([X,Y], (setup_call_cleanup(true, (X=1;X=2), writeq(a)), setup_call_cleanup(true,(Y=1;Y=2),writeq(b)), !), [[1,1]]),
+    count_cleanups(true, 1),
+    count_cleanups(false, 1),
+    count_cleanups(throw(e), 1),
+    count_cleanups(( member(X, [1,2,3]), X > 3 ), 1),
+    count_cleanups(member(_, [1,2,3]), 1),
+    count_exhausted_cleanups(1),
+    count_call_cleanups(true, 1),
+    count_call_cleanups(false, 1),
+    count_call_cleanups(throw(e), 1),
+    count_call_cleanups(member(_, [1,2,3]), 1),
+    count_exhausted_call_cleanups(1),
+    \+ \+ ( reset_cleanups,
+            call_cleanup(true, count_cleanup),
+            cleanups(1) ),
+    \+ \+ ( reset_cleanups,
+            call_cleanup(member(_, [1,2,3]), count_cleanup),
+            cleanups(0),
+            ! ).
 
 :- initialization(test_queries_on_setup_call_cleanup).
@@ -0,0 +1,71 @@
+:- module(tabling_tests, []).
+
+:- use_module(library(lists)).
+:- use_module(library(tabling)).
+
+:- use_module(test_framework).
+
+% a weighted graph with cycles, so the untabled definitions below would
+% not terminate.
+edge(a, b, 4).
+edge(a, c, 1).
+edge(c, b, 2).
+edge(b, d, 1).
+edge(c, d, 5).
+edge(d, a, 3).
+
+:- table shortest(_,_,min).
+
+shortest(X, Y, D) :-
+    edge(X, Y, D).
+shortest(X, Z, D) :-
+    shortest(X, Y, D0),
+    edge(Y, Z, D1),
+    D is D0 + D1.
+
+% a weighted DAG.
+dag_edge(s, u, 2).
+dag_edge(s, v, 5).
+dag_edge(u, v, 1).
+dag_edge(u, t, 7).
+dag_edge(v, t, 3).
+
+:- table longest(_,_,max).
+
+longest(X, Y, D) :-
+    dag_edge(X, Y, D).
+longest(X, Z, D) :-
+    longest(X, Y, D0),
+    dag_edge(Y, Z, D1),
+    D is D0 + D1.
+
+:- table connected/2.
+
+connected(X, Y) :-
+    edge(X, Y, _).
+connected(X, Z) :-
+    connected(X, Y),
+    edge(Y, Z, _).
+
+test("min keeps the shortest distance to each node", (
+    findall(Y-D, shortest(a, Y, D), Ds0),
+    sort(Ds0, Ds),
+    Ds == [a-7, b-3, c-1, d-4]
+)).
+
+test("min answers are found for a bound indexed argument", (
+    findall(D, shortest(c, a, D), Ds),
+    Ds == [6]
+)).
+
+test("max keeps the longest distance to each node", (
+    findall(Y-D, longest(s, Y, D), Ds0),
+    sort(Ds0, Ds),
+    Ds == [t-9, u-2, v-5]
+)).
+
+test("tables without answer modes keep every answer", (
+    findall(Y, connected(a, Y), Ys0),
+    sort(Ys0, Ys),
+    Ys == [a, b, c, d]
+)).
@@ -0,0 +1,43 @@
+:- module(terms_tests, []).
+
+:- use_module(library(terms)).
+
+:- use_module(test_framework).
+
+test("subsumes_term/2 accepts a more general term", (
+    subsumes_term(f(_), f(a)),
+    subsumes_term(f(A, A), f(b, b)),
+    subsumes_term(g(_, _), g(Y, Y)),
+    var(A),
+    var(Y)
+)).
+
+test("subsumes_term/2 rejects a more specific term", (
+    \+ subsumes_term(f(a), f(_)),
+    \+ subsumes_term(f(A, 2), f(2, A)),
+    \+ subsumes_term(f(B, B), f(a, b)),
+    var(A),
+    var(B)
+)).
+
+test("subsumes_term/2 does not bind either term", (
+    G = f(X, Y),
+    S = f(a, Z),
+    subsumes_term(G, S),
+    var(X),
+    var(Y),
+    var(Z)
+)).
+
+test("subsumes/2 keeps the bindings of the general term", (
+    subsumes(f(X, Y), f(a, Z)),
+    X == a,
+    Y == Z,
+    var(Z)
+)).
+
+test("subsumes/2 fails without binding on a more specific term", (
+    \+ subsumes(f(a, Y), f(X, b)),
+    var(X),
+    var(Y)
+)).
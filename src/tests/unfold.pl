@@ -0,0 +1,51 @@
+:- module(unfold_tests, []).
+
+:- use_module(library(diag)).
+:- use_module(library(lists)).
+:- use_module(library(unfold)).
+
+:- use_module(test_framework).
+
+colour(X) :- member(X, [red, green, blue]).
+
+first_point(X) :- memberchk(point(X, _), [point(1, a), point(2, b), point(1, c)]).
+
+prefixed(Ys, Zs) :- append([a, B], Ys, Zs), B = b.
+
+calls(PI, Name/Arity) :-
+    wam_instructions(unfold_tests:PI, Is),
+    (   memberchk(call(Name, Arity), Is)
+    ;   memberchk(execute(Name, Arity), Is)
+    ).
+
+test("member/2 over a ground list is unfolded", (
+    \+ calls(colour/1, member/2),
+    unfold_goal(member(X, [a, b]), G),
+    G == (X = a ; X = b)
+)).
+
+test("unfolding member/2 keeps the solutions and their order", (
+    findall(X, colour(X), Xs),
+    findall(X, call(member, X, [red, green, blue]), Ys),
+    Xs == Ys
+)).
+
+test("unfolding memberchk/2 commits to the first match", (
+    \+ calls(first_point/1, memberchk/2),
+    findall(X, first_point(X), Xs),
+    Xs == [1]
+)).
+
+test("unfolding append/3 builds the list in place", (
+    \+ calls(prefixed/2, append/3),
+    prefixed([c], Zs),
+    Zs == [a, b, c]
+)).
+
+test("goals with unknown lists are left alone", (
+    \+ unfold_goal(member(_, _), _),
+    \+ unfold_goal(member(_, [a | _]), _),
+    \+ unfold_goal(memberchk(_, [_, a]), _),
+    \+ unfold_goal(append([a | T], _, _), _),
+    var(T)
+)).
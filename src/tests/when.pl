@@ -2,6 +2,7 @@
 
 :- module(when_tests, []).
 
+:- use_module(library(iso_ext)).
 :- use_module(library(when)).
 
 :- use_module(test_framework).
@@ -79,3 +80,29 @@ test("multiple when/2 on same variable",(
     B = 1,
     Run2 == true
 )).
+
+test("?=/2 succeeds once equality is decided",(
+    ?=(a, a),
+    ?=(a, b),
+    ?=(X, X),
+    ?=(f(X, a), f(_, b)),
+    \+ ?=(X, a),
+    \+ ?=(f(X), f(_)),
+    var(X)
+)).
+
+test("condition ?=/2 true after binding",(
+    when(?=(A, B), Run = true),
+    var(Run),
+    A = a,
+    var(Run),
+    B = b,
+    Run == true
+)).
+
+test("condition ?=/2 true after aliasing",(
+    when(?=(A, B), Run = true),
+    var(Run),
+    A = B,
+    Run == true
+)).
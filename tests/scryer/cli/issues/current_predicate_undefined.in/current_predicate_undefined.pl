@@ -0,0 +1,12 @@
+p :- q.
+
+test :-
+    (  current_predicate(q/0) ->
+       write(q_defined)
+    ;  write(q_undefined)
+    ),
+    nl,
+    findall(N, current_predicate(N/0), Ns0),
+    sort(Ns0, Ns),
+    write(Ns),
+    nl.
@@ -0,0 +1,24 @@
+:- use_module(library(format)).
+
+:- dynamic(app/3).
+
+app([], Ys, Ys).
+app([X|Xs], Ys, [X|Zs]) :-
+    app(Xs, Ys, Zs).
+
+:- dynamic(classify/2).
+
+classify(X, small) :- X < 10, !.
+classify(X, Class) :-
+    (   X < 100 -> Class = medium
+    ;   Class = large
+    ).
+
+% static predicates are listed from the source terms kept by the
+% loader.
+len([], 0).
+len([_|Xs], N) :-
+    len(Xs, N0),
+    N is N0 + 1.
+
+:- initialization((listing(app/3), listing(classify/2), listing(len/2))).
@@ -0,0 +1,15 @@
+:- use_module(library(format)).
+
+:- dynamic(color/1).
+
+color(red).
+color(green).
+
+% static predicates are listed from the source terms kept by the
+% loader, in load order along with the dynamic ones.
+static_fact(1).
+static_fact(2).
+
+:- initialization((assertz(shade(dark)),
+                   assertz((shade(X) :- color(X))),
+                   listing)).
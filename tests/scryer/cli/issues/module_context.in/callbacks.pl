@@ -0,0 +1,23 @@
+:- module(callbacks, [run/1, twice/1, run_in/2]).
+
+:- use_module(library(iso_ext)).
+
+:- meta_predicate(run(0)).
+
+run(Goal) :-
+    call(Goal).
+
+:- meta_predicate(twice(0)).
+
+twice(Goal) :-
+    call(Goal),
+    call(Goal).
+
+% resolve the meta arguments of twice/1 in Module, whatever the
+% caller of run_in/2.
+run_in(Module, Goal) :-
+    @(twice(Goal), Module).
+
+greet :-
+    write(callbacks_greet),
+    nl.
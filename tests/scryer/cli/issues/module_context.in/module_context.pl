@@ -0,0 +1,16 @@
+:- use_module(callbacks).
+:- use_module(library(iso_ext)).
+:- use_module(library(lists)).
+
+greet :-
+    write(user_greet),
+    nl.
+
+:- initialization((run(greet),
+                   run_in(user, greet),
+                   run_in(callbacks, greet),
+                   @(twice(greet), callbacks),
+                   @(maplist(write, [a,b]), user),
+                   nl,
+                   call(callbacks:(true, forall(true, greet))),
+                   catch(@(true, 1), error(E, _), (write(E), nl)))).
@@ -0,0 +1,17 @@
+:- use_module(library(charsio)).
+:- use_module(library(format)).
+:- use_module(library(iso_ext)).
+
+read_lines :-
+    setup_call_cleanup(open('lines.txt', read, S),
+                       ( read_line_to_string(S, L1),
+                         read_line_to_codes(S, L2),
+                         read_line_to_string(S, L3),
+                         read_line_to_string(S, L4),
+                         read_line_to_string(S, L5),
+                         read_line_to_codes(S, L6)
+                       ),
+                       close(S)),
+    format("~q~n~q~n~q~n~q~n~q~n~q~n", [L1, L2, L3, L4, L5, L6]).
+
+:- initialization(read_lines).
@@ -0,0 +1,14 @@
+:- use_module(library(unfold)).
+
+% not the member/2 of library(lists), so calls to it must not be
+% unfolded.
+member(X, [_,X|_]).
+
+second(X) :- member(X, [a,b,c]).
+
+:- initialization((findall(X, second(X), Xs),
+                   write(Xs),
+                   nl,
+                   findall(X, member(X, [a,b,c]), Ys),
+                   write(Ys),
+                   nl)).